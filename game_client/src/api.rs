@@ -0,0 +1,246 @@
+use futures_util::StreamExt;
+use game_kernel::*;
+use reqwest::Client;
+use tokio::sync::watch;
+use tokio_tungstenite::{connect_async, tungstenite::Message};
+
+use crate::config::{Config, Transport};
+use crate::grpc_api::GrpcApi;
+
+// ===================================================================================
+// TRANSPORTE SELECIONADO
+// ===================================================================================
+
+pub enum Api {
+    Rest(RestApi),
+    Grpc(Box<GrpcApi>),
+}
+
+impl Api {
+    pub fn new(config: &Config) -> Result<Self, String> {
+        match config.transport {
+            Transport::Rest => Ok(Api::Rest(RestApi::new(&config.server_url, &config.move_url))),
+            Transport::Grpc => Ok(Api::Grpc(Box::new(GrpcApi::new(&config.server_url, &config.move_url)?))),
+        }
+    }
+
+    pub fn ws_url(&self, room_id: RoomId) -> Option<String> {
+        match self {
+            Api::Rest(api) => Some(api.ws_url(room_id)),
+            Api::Grpc(_) => None,
+        }
+    }
+
+    pub async fn find_room(&self) -> Result<RoomId, String> {
+        match self {
+            Api::Rest(api) => api.find_room().await.map_err(|e| e.to_string()),
+            Api::Grpc(_) => Ok(GrpcApi::ROOM_ID),
+        }
+    }
+
+    pub async fn join(&self, room_id: RoomId) -> Result<JoinResponse, String> {
+        match self {
+            Api::Rest(api) => api.join(room_id).await,
+            Api::Grpc(api) => api.join().await,
+        }
+    }
+
+    pub async fn add_ai_player(&self, room_id: RoomId, difficulty: Difficulty) -> Result<Player, String> {
+        match self {
+            Api::Rest(api) => api.add_ai_player(room_id, difficulty).await,
+            Api::Grpc(_) => Err("IAs não estão disponíveis com o transporte gRPC".to_string()),
+        }
+    }
+
+    pub async fn fetch_state_update(&self, room_id: RoomId, since: Option<u64>) -> Result<StateUpdate, String> {
+        match self {
+            Api::Rest(api) => api.fetch_state_update(room_id, since).await.map_err(|e| e.to_string()),
+            Api::Grpc(api) => api.fetch_state().await.map(StateUpdate::Snapshot),
+        }
+    }
+
+    pub async fn fetch_replay(&self, room_id: RoomId) -> Result<ReplayExport, String> {
+        match self {
+            Api::Rest(api) => api.fetch_replay(room_id).await.map_err(|e| e.to_string()),
+            Api::Grpc(_) => Err("Replays não estão disponíveis com o transporte gRPC".to_string()),
+        }
+    }
+
+    pub async fn request_rematch(
+        &self,
+        room_id: RoomId,
+        session_token: &str,
+        finished: &GameState,
+    ) -> Result<(), String> {
+        match self {
+            Api::Rest(api) => api.request_rematch(room_id, session_token).await,
+            Api::Grpc(api) => api.request_rematch(session_token, finished).await,
+        }
+    }
+
+    pub async fn send_move(
+        &self,
+        room_id: RoomId,
+        session_token: &str,
+        player_id: PlayerId,
+        direction: Direction,
+    ) -> Result<(), String> {
+        match self {
+            Api::Rest(api) => api.send_move(room_id, session_token, player_id, direction).await,
+            Api::Grpc(api) => api.send_move(session_token, player_id, direction).await,
+        }
+    }
+}
+
+// ===================================================================================
+// CLIENTE DA API REST
+// ===================================================================================
+
+pub struct RestApi {
+    client: Client,
+    server_url: String,
+    move_url: String,
+}
+
+impl RestApi {
+    pub fn new(server_url: &str, move_url: &str) -> Self {
+        RestApi { client: Client::new(), server_url: server_url.to_string(), move_url: move_url.to_string() }
+    }
+
+    fn room_url(&self, room_id: RoomId, path: &str) -> String {
+        format!("{}/game/{}/{}", self.server_url, room_id, path)
+    }
+
+    pub fn ws_url(&self, room_id: RoomId) -> String {
+        self.room_url(room_id, "ws").replacen("http", "ws", 1)
+    }
+
+    pub async fn find_room(&self) -> reqwest::Result<RoomId> {
+        let rooms_url = format!("{}/game/rooms", self.server_url);
+        let rooms: Vec<RoomSummary> = self.client.get(&rooms_url).send().await?.json().await?;
+        if let Some(room) = rooms.iter().find(|room| room.is_open()) {
+            return Ok(room.id);
+        }
+
+        let room: RoomSummary = self
+            .client
+            .post(&rooms_url)
+            .json(&RoomConfig::default())
+            .send()
+            .await?
+            .json()
+            .await?;
+        Ok(room.id)
+    }
+
+    pub async fn join(&self, room_id: RoomId) -> Result<JoinResponse, String> {
+        let resp = self
+            .client
+            .post(self.room_url(room_id, "join"))
+            .send()
+            .await
+            .map_err(|e| format!("Não foi possível conectar ao servidor: {}", e))?;
+
+        if !resp.status().is_success() {
+            let body = resp.text().await.unwrap_or_default();
+            return Err(format!("Falha ao entrar no jogo (servidor respondeu com erro): {}", body));
+        }
+        resp.json().await.map_err(|e| format!("Resposta inválida do servidor: {}", e))
+    }
+
+    pub async fn add_ai_player(&self, room_id: RoomId, difficulty: Difficulty) -> Result<Player, String> {
+        let resp = self
+            .client
+            .post(self.room_url(room_id, "ai"))
+            .json(&AddAiRequest { difficulty, seed: None })
+            .send()
+            .await
+            .map_err(|e| format!("Erro de rede: {}", e))?;
+
+        if resp.status().is_success() {
+            return resp.json().await.map_err(|e| format!("Resposta inválida do servidor: {}", e));
+        }
+        match resp.json::<ErrorBody>().await {
+            Ok(body) => Err(body.erro),
+            Err(_) => Err("Não foi possível adicionar a IA".to_string()),
+        }
+    }
+
+    pub async fn fetch_state_update(&self, room_id: RoomId, since: Option<u64>) -> reqwest::Result<StateUpdate> {
+        let url = match since {
+            Some(version) => format!("{}?since={}", self.room_url(room_id, "state/updates"), version),
+            None => self.room_url(room_id, "state/updates"),
+        };
+        self.client.get(url).send().await?.json().await
+    }
+
+    pub async fn fetch_replay(&self, room_id: RoomId) -> reqwest::Result<ReplayExport> {
+        self.client.get(self.room_url(room_id, "replay")).send().await?.error_for_status()?.json().await
+    }
+
+    pub async fn request_rematch(&self, room_id: RoomId, session_token: &str) -> Result<(), String> {
+        let resp = self
+            .client
+            .post(self.room_url(room_id, "restart"))
+            .bearer_auth(session_token)
+            .json(&RestartRequest { keep_players: true })
+            .send()
+            .await
+            .map_err(|e| format!("Erro de rede: {}", e))?;
+
+        if resp.status().is_success() {
+            return Ok(());
+        }
+        match resp.json::<ErrorBody>().await {
+            Ok(body) => Err(body.erro),
+            Err(_) => Err("Não foi possível reiniciar a partida".to_string()),
+        }
+    }
+
+    pub async fn send_move(
+        &self,
+        room_id: RoomId,
+        session_token: &str,
+        player_id: PlayerId,
+        direction: Direction,
+    ) -> Result<(), String> {
+        let payload = MovePayload { player_id, direction };
+        let resp = self
+            .client
+            .post(format!("{}/game/{}/move", self.move_url, room_id))
+            .bearer_auth(session_token)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| format!("Erro de rede: {}", e))?;
+
+        if resp.status().is_success() {
+            return Ok(());
+        }
+        match resp.json::<ErrorBody>().await {
+            Ok(body) => Err(body.erro),
+            Err(_) => Err("Movimento recusado pelo servidor".to_string()),
+        }
+    }
+}
+
+pub async fn stream_game_state(ws_url: String, state_tx: watch::Sender<Option<GameState>>) {
+    let Ok((mut socket, _)) = connect_async(ws_url).await else {
+        return;
+    };
+
+    let mut game_state = None;
+    while let Some(Ok(message)) = socket.next().await {
+        match message {
+            Message::Text(text) => {
+                if let Ok(update) = serde_json::from_str::<StateUpdate>(&text)
+                    && update.apply_to(&mut game_state)
+                {
+                    state_tx.send_replace(game_state.clone());
+                }
+            }
+            Message::Close(_) => break,
+            _ => {}
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use game_kernel::*;
+
+use crate::api::Api;
+use crate::config::BotConfig;
+
+const WAIT_INTERVAL: Duration = Duration::from_millis(250);
+
+// ===================================================================================
+// MODO BOT (SEM INTERFACE)
+// ===================================================================================
+
+#[derive(Default)]
+struct BotStats {
+    accepted: usize,
+    rejected: usize,
+    stuck_turns: usize,
+}
+
+pub async fn run_bot(api: &Api, config: &BotConfig, join_response: JoinResponse) {
+    let room_id = join_response.room_id;
+    let player_id = join_response.player.id;
+    let seed = config.seed.unwrap_or_else(|| {
+        SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_nanos() as u64).unwrap_or_default()
+    });
+    let mut strategy = config.strategy.build(seed);
+    let mut stats = BotStats::default();
+    let mut game_state: Option<GameState> = None;
+    let started_at = Instant::now();
+
+    println!("Bot '{}' entrou na sala {} como Jogador {} (semente {})", strategy.name(), room_id, player_id, seed);
+
+    loop {
+        let update = tokio::select! {
+            update = api.fetch_state_update(room_id, game_state.as_ref().map(|state| state.version)) => update,
+            _ = tokio::signal::ctrl_c() => break,
+        };
+        match update {
+            Ok(update) => {
+                if !update.apply_to(&mut game_state) {
+                    game_state = None;
+                    continue;
+                }
+            }
+            Err(e) => {
+                eprintln!("Erro ao buscar estado: {}", e);
+                tokio::time::sleep(WAIT_INTERVAL).await;
+                continue;
+            }
+        }
+        let Some(state) = &game_state else { continue };
+
+        match state.status {
+            GameStatus::Finished => break,
+            GameStatus::WaitingForPlayers => {
+                tokio::time::sleep(WAIT_INTERVAL).await;
+                continue;
+            }
+            GameStatus::InProgress => {}
+        }
+
+        match strategy.choose_move(state, player_id) {
+            Some(direction) => match api.send_move(room_id, &join_response.token, player_id, direction).await {
+                Ok(()) => stats.accepted += 1,
+                Err(_) => stats.rejected += 1,
+            },
+            None => stats.stuck_turns += 1,
+        }
+        tokio::time::sleep(config.delay).await;
+    }
+
+    print_summary(strategy.name(), player_id, &stats, started_at.elapsed(), game_state.as_ref());
+}
+
+fn print_summary(strategy: &str, player_id: PlayerId, stats: &BotStats, elapsed: Duration, state: Option<&GameState>) {
+    println!();
+    println!("=== Resumo do bot ({}) ===", strategy);
+    println!("Jogador: {}", player_id);
+    println!("Duração: {:.1}s", elapsed.as_secs_f64());
+    println!("Jogadas aceitas: {}", stats.accepted);
+    println!("Jogadas recusadas: {}", stats.rejected);
+    println!("Turnos sem jogada possível: {}", stats.stuck_turns);
+
+    let Some(state) = state else { return };
+    println!("Status final: {:?}", state.status);
+    for (player, score) in state.ranking() {
+        let marker = if player.id == player_id { " <- bot" } else { "" };
+        println!("  Jogador {}: {} células{}", player.id, score, marker);
+    }
+    if let Some(winner) = state.winner() {
+        println!("Vencedor: Jogador {}", winner);
+    }
+}
@@ -0,0 +1,290 @@
+use std::path::PathBuf;
+use std::time::Duration;
+
+use clap::{Parser, Subcommand, ValueEnum};
+use game_kernel::{Difficulty, RoomId, StrategyKind};
+use ratatui::style::Color;
+use serde::Deserialize;
+
+// ===================================================================================
+// VALORES PADRÃO
+// ===================================================================================
+
+const DEFAULT_SERVER_URL: &str = "http://localhost:8000";
+const DEFAULT_GRPC_STATE_URL: &str = "http://localhost:50051";
+const DEFAULT_GRPC_MOVE_URL: &str = "http://localhost:50052";
+const DEFAULT_POLL_INTERVAL_MS: u64 = 200;
+const DEFAULT_PLAYER_NAME: &str = "Jogador";
+const DEFAULT_BOT_DELAY_MS: u64 = 100;
+const CONFIG_DIR_NAME: &str = "pspd";
+const CONFIG_FILE_NAME: &str = "game_client.toml";
+
+// ===================================================================================
+// LINHA DE COMANDO
+// ===================================================================================
+
+#[derive(Parser)]
+#[command(name = "game_client", about = "Cliente de terminal do jogo de conquista de território")]
+pub struct Cli {
+    /// Arquivo de configuração (padrão: <diretório de configuração do usuário>/pspd/game_client.toml)
+    #[arg(long, env = "GAME_CLIENT_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// Protocolo usado para falar com os serviços: API REST ou gRPC direto
+    #[arg(long, env = "GAME_CLIENT_TRANSPORT", value_enum)]
+    transport: Option<Transport>,
+
+    /// URL do gateway ou do Serviço B (no gRPC, o GameStateService)
+    #[arg(long, env = "GAME_CLIENT_SERVER")]
+    server: Option<String>,
+
+    /// URL usada para enviar movimentos (padrão: a mesma de --server; no gRPC, o GameMoveService)
+    #[arg(long, env = "GAME_CLIENT_MOVE_SERVER")]
+    move_server: Option<String>,
+
+    /// Sala em que o cliente deve entrar (padrão: primeira sala aberta)
+    #[arg(long, env = "GAME_CLIENT_ROOM")]
+    room: Option<RoomId>,
+
+    /// Nome exibido na interface
+    #[arg(long, env = "GAME_CLIENT_NAME")]
+    name: Option<String>,
+
+    /// Recebe o estado via WebSocket (push) ou por consultas periódicas (poll)
+    #[arg(long, env = "GAME_CLIENT_MODE", value_enum)]
+    mode: Option<UpdateMode>,
+
+    /// Intervalo entre consultas no modo poll, em milissegundos
+    #[arg(long, env = "GAME_CLIENT_POLL_MS")]
+    poll_ms: Option<u64>,
+
+    /// Tema de cores da interface
+    #[arg(long, env = "GAME_CLIENT_THEME", value_enum)]
+    theme: Option<Theme>,
+
+    /// Preenche as vagas restantes da sala com IAs do servidor (easy, medium ou hard)
+    #[arg(long, env = "GAME_CLIENT_FILL_WITH_AI")]
+    fill_with_ai: Option<Difficulty>,
+
+    /// Joga sem interface usando uma estratégia (random, greedy ou flood-fill)
+    #[arg(long, env = "GAME_CLIENT_BOT")]
+    bot: Option<StrategyKind>,
+
+    /// Semente da estratégia do bot (padrão: derivada do relógio)
+    #[arg(long, env = "GAME_CLIENT_BOT_SEED")]
+    bot_seed: Option<u64>,
+
+    /// Pausa entre jogadas do bot, em milissegundos
+    #[arg(long, env = "GAME_CLIENT_BOT_DELAY_MS", default_value_t = DEFAULT_BOT_DELAY_MS)]
+    bot_delay_ms: u64,
+
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand)]
+pub enum Command {
+    /// Reproduz uma partida a partir do ID da sala ou de um arquivo exportado
+    Replay { source: String },
+}
+
+#[derive(ValueEnum, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum Transport {
+    Rest,
+    Grpc,
+}
+
+#[derive(ValueEnum, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum UpdateMode {
+    Push,
+    Poll,
+}
+
+#[derive(ValueEnum, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(rename_all = "kebab-case")]
+pub enum Theme {
+    Dark,
+    Light,
+    HighContrast,
+}
+
+// ===================================================================================
+// ARQUIVO DE CONFIGURAÇÃO
+// ===================================================================================
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct FileConfig {
+    transport: Option<Transport>,
+    server: Option<String>,
+    move_server: Option<String>,
+    room: Option<RoomId>,
+    name: Option<String>,
+    mode: Option<UpdateMode>,
+    poll_ms: Option<u64>,
+    theme: Option<Theme>,
+    keys: KeyMap,
+}
+
+#[derive(Deserialize, Clone, Copy, Debug)]
+#[serde(default, deny_unknown_fields)]
+pub struct KeyMap {
+    pub up: char,
+    pub down: char,
+    pub left: char,
+    pub right: char,
+    pub quit: char,
+    pub rematch: char,
+}
+
+impl Default for KeyMap {
+    fn default() -> Self {
+        KeyMap { up: 'w', down: 's', left: 'a', right: 'd', quit: 'q', rematch: 'r' }
+    }
+}
+
+fn load_file_config(path: Option<PathBuf>) -> Result<FileConfig, String> {
+    let explicit = path.is_some();
+    let Some(path) = path.or_else(|| dirs::config_dir().map(|dir| dir.join(CONFIG_DIR_NAME).join(CONFIG_FILE_NAME)))
+    else {
+        return Ok(FileConfig::default());
+    };
+
+    match std::fs::read_to_string(&path) {
+        Ok(contents) => toml::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound && !explicit => Ok(FileConfig::default()),
+        Err(e) => Err(format!("{}: {}", path.display(), e)),
+    }
+}
+
+// ===================================================================================
+// CONFIGURAÇÃO FINAL
+// ===================================================================================
+
+pub struct Palette {
+    pub neutral: Color,
+    pub status: Color,
+    pub error: Color,
+    pub marker_fg: Color,
+    pub marker_bg: Color,
+}
+
+impl Theme {
+    pub fn palette(self) -> Palette {
+        match self {
+            Theme::Dark => Palette {
+                neutral: Color::DarkGray,
+                status: Color::Yellow,
+                error: Color::Red,
+                marker_fg: Color::White,
+                marker_bg: Color::Black,
+            },
+            Theme::Light => Palette {
+                neutral: Color::Gray,
+                status: Color::Blue,
+                error: Color::Red,
+                marker_fg: Color::Black,
+                marker_bg: Color::White,
+            },
+            Theme::HighContrast => Palette {
+                neutral: Color::Black,
+                status: Color::White,
+                error: Color::LightRed,
+                marker_fg: Color::Black,
+                marker_bg: Color::LightYellow,
+            },
+        }
+    }
+}
+
+pub struct BotConfig {
+    pub strategy: StrategyKind,
+    pub seed: Option<u64>,
+    pub delay: Duration,
+}
+
+pub struct Config {
+    pub transport: Transport,
+    pub server_url: String,
+    pub move_url: String,
+    pub room_id: Option<RoomId>,
+    pub player_name: String,
+    pub update_mode: UpdateMode,
+    pub poll_interval: Duration,
+    pub palette: Palette,
+    pub keys: KeyMap,
+    pub fill_with_ai: Option<Difficulty>,
+    pub bot: Option<BotConfig>,
+}
+
+impl Cli {
+    pub fn into_config(self) -> Result<(Config, Option<Command>), String> {
+        let file = load_file_config(self.config)?;
+
+        let transport = self.transport.or(file.transport).unwrap_or(Transport::Rest);
+        let default_server_url = match transport {
+            Transport::Rest => DEFAULT_SERVER_URL,
+            Transport::Grpc => DEFAULT_GRPC_STATE_URL,
+        };
+        let server_url = self.server.or(file.server).unwrap_or_else(|| default_server_url.to_string());
+        let server_url = validate_url(&server_url)?;
+        let move_url = match (self.move_server.or(file.move_server), transport) {
+            (Some(url), _) => validate_url(&url)?,
+            (None, Transport::Rest) => server_url.clone(),
+            (None, Transport::Grpc) => DEFAULT_GRPC_MOVE_URL.to_string(),
+        };
+
+        // O GameStateService do gRPC não tem WebSocket nem IAs: o estado só chega por consulta.
+        let update_mode = match (self.mode.or(file.mode), transport) {
+            (Some(UpdateMode::Push), Transport::Grpc) => {
+                return Err("O modo push não está disponível com o transporte gRPC; use --mode poll".to_string());
+            }
+            (Some(mode), _) => mode,
+            (None, Transport::Rest) => UpdateMode::Push,
+            (None, Transport::Grpc) => UpdateMode::Poll,
+        };
+        if transport == Transport::Grpc && self.fill_with_ai.is_some() {
+            return Err("--fill-with-ai não está disponível com o transporte gRPC".to_string());
+        }
+
+        let poll_ms = self.poll_ms.or(file.poll_ms).unwrap_or(DEFAULT_POLL_INTERVAL_MS);
+        if poll_ms == 0 {
+            return Err("O intervalo de consulta deve ser maior que zero".to_string());
+        }
+
+        let player_name = self
+            .name
+            .or(file.name)
+            .or_else(|| std::env::var("USER").ok())
+            .unwrap_or_else(|| DEFAULT_PLAYER_NAME.to_string());
+
+        let config = Config {
+            transport,
+            server_url,
+            move_url,
+            room_id: self.room.or(file.room),
+            player_name,
+            update_mode,
+            poll_interval: Duration::from_millis(poll_ms),
+            palette: self.theme.or(file.theme).unwrap_or(Theme::Dark).palette(),
+            keys: file.keys,
+            fill_with_ai: self.fill_with_ai,
+            bot: self.bot.map(|strategy| BotConfig {
+                strategy,
+                seed: self.bot_seed,
+                delay: Duration::from_millis(self.bot_delay_ms),
+            }),
+        };
+        Ok((config, self.command))
+    }
+}
+
+fn validate_url(url: &str) -> Result<String, String> {
+    let url = url.trim_end_matches('/');
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(format!("URL inválida: '{}' (use http:// ou https://)", url));
+    }
+    Ok(url.to_string())
+}
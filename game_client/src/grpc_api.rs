@@ -0,0 +1,154 @@
+use game_kernel::grpc::gamemovement::{self, game_move_service_client::GameMoveServiceClient};
+use game_kernel::grpc::gamestate::{self, game_state_service_client::GameStateServiceClient};
+use game_kernel::*;
+use tonic::transport::Channel;
+use tonic::{Request, Status};
+
+// ===================================================================================
+// CLIENTE gRPC (GameStateService E GameMoveService)
+// ===================================================================================
+
+pub struct GrpcApi {
+    state: GameStateServiceClient<Channel>,
+    moves: GameMoveServiceClient<Channel>,
+}
+
+fn describe(status: Status) -> String {
+    if status.message().is_empty() {
+        format!("Erro de gRPC: {}", status.code())
+    } else {
+        status.message().to_string()
+    }
+}
+
+// Servidores que não autenticam devolvem um token vazio, e nada é enviado.
+fn with_session<T>(message: T, session_token: &str) -> Result<Request<T>, String> {
+    let mut request = Request::new(message);
+    if !session_token.is_empty() {
+        let value = format!("Bearer {}", session_token)
+            .parse()
+            .map_err(|_| "Token de sessão inválido".to_string())?;
+        request.metadata_mut().insert("authorization", value);
+    }
+    Ok(request)
+}
+
+fn channel(url: &str) -> Result<Channel, String> {
+    Channel::from_shared(url.to_string())
+        .map(|endpoint| endpoint.connect_lazy())
+        .map_err(|e| format!("Endereço gRPC inválido '{}': {}", url, e))
+}
+
+impl GrpcApi {
+    // A implantação gRPC tem uma única partida, sem salas.
+    pub const ROOM_ID: RoomId = 0;
+
+    pub fn new(state_url: &str, move_url: &str) -> Result<Self, String> {
+        Ok(GrpcApi {
+            state: GameStateServiceClient::new(channel(state_url)?),
+            moves: GameMoveServiceClient::new(channel(move_url)?),
+        })
+    }
+
+    pub async fn join(&self) -> Result<JoinResponse, String> {
+        let response = self
+            .state
+            .clone()
+            .join_game(gamestate::JoinGameRequest {})
+            .await
+            .map_err(|status| format!("Falha ao entrar no jogo: {}", describe(status)))?
+            .into_inner();
+
+        let player = match response.player {
+            Some(player) => Player::try_from(player).map_err(|e| format!("Resposta inválida do servidor: {}", e))?,
+            None => return Err(format!("Falha ao entrar no jogo: {}", response.error)),
+        };
+        Ok(JoinResponse { room_id: Self::ROOM_ID, player, token: response.token })
+    }
+
+    async fn fetch_wire_state(&self) -> Result<gamestate::GameState, String> {
+        self.state
+            .clone()
+            .get_game_state(gamestate::GetGameStateRequest {})
+            .await
+            .map_err(describe)?
+            .into_inner()
+            .state
+            .ok_or_else(|| WireError::MissingField { field: "GameStateResponse.state" }.to_string())
+    }
+
+    pub async fn fetch_state(&self) -> Result<GameState, String> {
+        GameState::try_from(self.fetch_wire_state().await?).map_err(|e| e.to_string())
+    }
+
+    pub async fn send_move(&self, session_token: &str, player_id: PlayerId, direction: Direction) -> Result<(), String> {
+        let state = GameState::try_from(self.fetch_wire_state().await?).map_err(|e| e.to_string())?;
+        let current_state = gamemovement::GameState::from(&state);
+        let direction = gamemovement::Direction::from(direction);
+
+        let validation = self
+            .moves
+            .clone()
+            .validate_move(gamemovement::ValidateMoveRequest {
+                current_state: Some(current_state.clone()),
+                player_id: player_id.into(),
+                direction: direction.into(),
+            })
+            .await
+            .map_err(describe)?
+            .into_inner();
+        if !validation.is_valid {
+            return Err(validation.error);
+        }
+
+        let execution = self
+            .moves
+            .clone()
+            .execute_move(with_session(
+                gamemovement::ExecuteMoveRequest {
+                    current_state: Some(current_state),
+                    player_id: player_id.into(),
+                    direction: direction.into(),
+                },
+                session_token,
+            )?)
+            .await
+            .map_err(describe)?
+            .into_inner();
+        if !execution.error.is_empty() {
+            return Err(execution.error);
+        }
+        Ok(())
+    }
+
+    // Um Serviço B que ignore `keep_players` esvazia a partida; nesse caso o estado da revanche
+    // é montado pelo kernel e gravado com UpdateGameState na versão lida após o reinício.
+    pub async fn request_rematch(&self, session_token: &str, finished: &GameState) -> Result<(), String> {
+        let mut client = self.state.clone();
+        client
+            .restart_game(with_session(gamestate::RestartGameRequest { keep_players: true }, session_token)?)
+            .await
+            .map_err(describe)?;
+
+        let restarted = self.fetch_state().await?;
+        if !restarted.players.is_empty() || finished.players.is_empty() {
+            return Ok(());
+        }
+        let mut rematch = finished.clone();
+        rematch.restart(true).map_err(|e| e.to_string())?;
+        (rematch.version, rematch.epoch) = (restarted.version, restarted.epoch);
+
+        let response = client
+            .update_game_state(with_session(
+                gamestate::UpdateGameStateRequest { state: Some(gamestate::GameState::from(&rematch)) },
+                session_token,
+            )?)
+            .await
+            .map_err(describe)?
+            .into_inner();
+        if !response.success {
+            return Err("O servidor não aceitou o estado da revanche".to_string());
+        }
+        Ok(())
+    }
+}
@@ -1,8 +1,7 @@
-use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
 use std::io::{self, stdout, Stdout};
-use std::time::Duration;
+use std::process::ExitCode;
 
+use clap::Parser;
 use crossterm::{
     event::{self, Event, KeyCode, KeyEventKind},
     execute,
@@ -12,124 +11,161 @@ use ratatui::{
     prelude::*,
     widgets::{Block, Borders, Paragraph, Widget},
 };
-use reqwest::Client;
-
-// ===================================================================================
-// ESTRUTURAS DE DADOS (Idênticas às do backend)
-// ===================================================================================
-
-type PlayerId = u8;
-
-#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
-pub enum GameStatus {
-    WaitingForPlayers,
-    InProgress,
-    Finished,
-}
-
-#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
-pub enum CellState {
-    Neutral,
-    Owned(PlayerId),
-}
+use game_kernel::*;
+use game_kernel::Direction;
+use std::time::Duration;
+use tokio::sync::watch;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct Player {
-    id: PlayerId,
-    x: usize,
-    y: usize,
-    color: String,
-}
+mod api;
+mod bot;
+mod config;
+mod grpc_api;
+mod replay;
 
-#[derive(Serialize, Deserialize, Clone, Debug)]
-pub struct GameState {
-    status: GameStatus,
-    width: usize,
-    height: usize,
-    grid: Vec<Vec<CellState>>,
-    players: HashMap<PlayerId, Player>,
-}
-
-#[derive(Serialize)]
-pub struct MovePayload<'a> {
-    player_id: PlayerId,
-    direction: &'a str,
-}
+use api::{stream_game_state, Api};
+use config::{Cli, Command, Config, Palette, UpdateMode};
 
 // ===================================================================================
-// CONSTANTES E CONFIGURAÇÃO
+// CONSTANTES
 // ===================================================================================
 
-const API_BASE_URL: &str = "http://localhost:8000";
+const PUSH_REDRAW_INTERVAL: Duration = Duration::from_millis(50);
 
 // ===================================================================================
 // FUNÇÃO PRINCIPAL
 // ===================================================================================
 
 #[tokio::main]
-async fn main() -> Result<(), Box<dyn std::error::Error>> {
-    let mut terminal = init_terminal()?;
-    let client = Client::new();
-
-    let my_player: Player = match client.post(format!("{}/game/join", API_BASE_URL)).send().await {
-        Ok(resp) => {
-            if resp.status().is_success() {
-                resp.json().await?
-            } else {
-                restore_terminal(&mut terminal)?;
-                eprintln!("Falha ao entrar no jogo (servidor respondeu com erro): {}", resp.text().await?);
-                return Ok(());
-            }
+async fn main() -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let (config, command) = match Cli::parse().into_config() {
+        Ok(parsed) => parsed,
+        Err(e) => {
+            eprintln!("Configuração inválida: {}", e);
+            return Ok(ExitCode::FAILURE);
         }
+    };
+    let api = match Api::new(&config) {
+        Ok(api) => api,
         Err(e) => {
-            restore_terminal(&mut terminal)?;
-            eprintln!("Não foi possível conectar ao servidor: {}", e);
-            return Ok(());
+            eprintln!("{}", e);
+            return Ok(ExitCode::FAILURE);
         }
     };
 
-    let my_player_id = my_player.id;
+    if let Some(Command::Replay { source }) = command {
+        return replay::run_replay(&api, &config, &source).await;
+    }
+
+    let join_response = match enter_room(&api, &config).await {
+        Ok(join_response) => join_response,
+        Err(e) => {
+            eprintln!("{}", e);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    if let Some(difficulty) = config.fill_with_ai {
+        while let Ok(player) = api.add_ai_player(join_response.room_id, difficulty).await {
+            println!("IA '{}' entrou como Jogador {}", difficulty, player.id);
+        }
+    }
+
+    if let Some(bot_config) = &config.bot {
+        bot::run_bot(&api, bot_config, join_response).await;
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    let mut terminal = init_terminal()?;
+    let room_id = join_response.room_id;
+    let my_player_id = join_response.player.id;
+    let session_token = join_response.token;
+    let keys = config.keys;
     let mut game_state: Option<GameState> = None;
+    let mut last_error: Option<String> = None;
+
+    // Sem canal de push (modo poll ou transporte sem WebSocket), ou com o stream encerrado,
+    // o estado é consultado a cada volta do loop.
+    let mut state_rx = None;
+    if config.update_mode == UpdateMode::Push
+        && let Some(ws_url) = api.ws_url(room_id)
+    {
+        let (state_tx, rx) = watch::channel(None);
+        tokio::spawn(stream_game_state(ws_url, state_tx));
+        state_rx = Some(rx);
+    }
 
     loop {
-        if let Ok(resp) = client.get(format!("{}/game/state", API_BASE_URL)).send().await {
-            if let Ok(state) = resp.json::<GameState>().await {
-                game_state = Some(state);
+        let push_alive = match state_rx.as_mut().map(|rx| (rx.has_changed(), rx)) {
+            Some((Ok(changed), rx)) => {
+                if changed {
+                    game_state = rx.borrow_and_update().clone();
+                }
+                true
             }
+            Some((Err(_), _)) => {
+                state_rx = None;
+                false
+            }
+            None => false,
+        };
+
+        if !push_alive
+            && let Ok(update) = api.fetch_state_update(room_id, game_state.as_ref().map(|state| state.version)).await
+            && !update.apply_to(&mut game_state)
+        {
+            game_state = None;
         }
 
         if let Some(state) = &game_state {
-            terminal.draw(|frame| ui(frame, state, my_player_id))?;
+            terminal.draw(|frame| ui(frame, state, my_player_id, &config, last_error.as_deref()))?;
         }
 
-        if event::poll(Duration::from_millis(200))? {
-            if let Event::Key(key) = event::read()? {
-                if key.kind == KeyEventKind::Press {
-                    let mut direction = None;
-                    match key.code {
-                        KeyCode::Char('w') | KeyCode::Up => direction = Some("UP"),
-                        KeyCode::Char('s') | KeyCode::Down => direction = Some("DOWN"),
-                        KeyCode::Char('a') | KeyCode::Left => direction = Some("LEFT"),
-                        KeyCode::Char('d') | KeyCode::Right => direction = Some("RIGHT"),
-                        KeyCode::Char('q') => break,
-                        _ => {}
-                    }
-
-                    if let Some(dir) = direction {
-                        let payload = MovePayload { player_id: my_player_id, direction: dir };
-                        let _ = client
-                            .post(format!("{}/game/move", API_BASE_URL))
-                            .json(&payload)
-                            .send()
-                            .await;
+        let wait = if push_alive { PUSH_REDRAW_INTERVAL } else { config.poll_interval };
+        if event::poll(wait)?
+            && let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            let mut direction = None;
+            match key.code {
+                KeyCode::Up => direction = Some(Direction::Up),
+                KeyCode::Down => direction = Some(Direction::Down),
+                KeyCode::Left => direction = Some(Direction::Left),
+                KeyCode::Right => direction = Some(Direction::Right),
+                KeyCode::Char(c) if c == keys.up => direction = Some(Direction::Up),
+                KeyCode::Char(c) if c == keys.down => direction = Some(Direction::Down),
+                KeyCode::Char(c) if c == keys.left => direction = Some(Direction::Left),
+                KeyCode::Char(c) if c == keys.right => direction = Some(Direction::Right),
+                KeyCode::Char(c) if c == keys.quit => break,
+                KeyCode::Char(c) if c == keys.rematch => {
+                    if let Some(finished) = game_state.as_ref().filter(|state| state.status == GameStatus::Finished) {
+                        last_error = api.request_rematch(room_id, &session_token, finished).await.err();
                     }
                 }
+                _ => {}
+            }
+
+            if let Some(dir) = direction {
+                last_error = match game_state.as_ref().map(|state| state.resolve_move(my_player_id, dir)) {
+                    Some(Err(err)) => Some(err.to_string()),
+                    _ => api.send_move(room_id, &session_token, my_player_id, dir).await.err(),
+                };
             }
         }
     }
 
     restore_terminal(&mut terminal)?;
-    Ok(())
+    Ok(ExitCode::SUCCESS)
+}
+
+async fn enter_room(api: &Api, config: &Config) -> Result<JoinResponse, String> {
+    let room_id = match config.room_id {
+        Some(room_id) => room_id,
+        None => api
+            .find_room()
+            .await
+            .map_err(|e| format!("Não foi possível encontrar uma sala: {}", e))?,
+    };
+    api.join(room_id).await
 }
 
 // ===================================================================================
@@ -150,19 +186,20 @@ fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> io::Re
     terminal.show_cursor()
 }
 
-fn ui(frame: &mut Frame, state: &GameState, my_id: PlayerId) {
-    let main_layout = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Length(3), Constraint::Min(0)])
+fn ui(frame: &mut Frame, state: &GameState, my_id: PlayerId, config: &Config, last_error: Option<&str>) {
+    let main_layout = Layout::vertical([Constraint::Length(3), Constraint::Min(0), Constraint::Length(1)])
         .split(frame.area());
 
     let status_text = match state.status {
-        GameStatus::WaitingForPlayers => format!("Aguardando jogadores... ({}/{})", state.players.len(), 2),
-        GameStatus::InProgress => format!("Jogo em andamento! Você é o Jogador {}", my_id),
-        GameStatus::Finished => "Fim de jogo!".to_string(),
+        GameStatus::WaitingForPlayers => format!("Aguardando jogadores... ({}/{})", state.players.len(), state.min_players),
+        GameStatus::InProgress => format!("Jogo em andamento! {}, você é o Jogador {}", config.player_name, my_id),
+        GameStatus::Finished => match state.winner() {
+            Some(id) => format!("Fim de jogo! Vencedor: Jogador {} ('{}' para jogar novamente)", id, config.keys.rematch),
+            None => format!("Fim de jogo! ('{}' para jogar novamente)", config.keys.rematch),
+        },
     };
     let status_widget = Paragraph::new(status_text)
-        .style(Style::default().fg(Color::Yellow))
+        .style(Style::default().fg(config.palette.status))
         .alignment(Alignment::Center)
         .block(Block::default().borders(Borders::ALL).title("Status"));
     frame.render_widget(status_widget, main_layout[0]);
@@ -172,20 +209,34 @@ fn ui(frame: &mut Frame, state: &GameState, my_id: PlayerId) {
     
     frame.render_widget(game_block, main_layout[1]);
     
-    let game_widget = GameWidget { state, my_id };
+    let game_widget = GameWidget { state, my_id, palette: &config.palette };
     frame.render_widget(game_widget, game_area);
+
+    if let Some(message) = last_error {
+        let error_widget = Paragraph::new(message).style(Style::default().fg(config.palette.error));
+        frame.render_widget(error_widget, main_layout[2]);
+    }
 }
 
+fn player_color(hex: &str) -> Color {
+    Color::Rgb(
+        u8::from_str_radix(&hex[1..3], 16).unwrap_or(255),
+        u8::from_str_radix(&hex[3..5], 16).unwrap_or(255),
+        u8::from_str_radix(&hex[5..7], 16).unwrap_or(255),
+    )
+}
 
 struct GameWidget<'a> {
     state: &'a GameState,
     my_id: PlayerId,
+    palette: &'a Palette,
 }
 
 impl Widget for GameWidget<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let state = self.state;
         let my_id = self.my_id;
+        let palette = self.palette;
 
         let cell_width = (area.width as usize / state.width).max(1);
         let cell_height = (area.height as usize / state.height).max(1);
@@ -196,15 +247,8 @@ impl Widget for GameWidget<'_> {
                 let symbol = "█";
 
                 let color = match cell_state {
-                    CellState::Neutral => Color::DarkGray,
-                    CellState::Owned(player_id) => {
-                        let player_color_str = &state.players.get(&player_id).unwrap().color;
-                        Color::Rgb(
-                            u8::from_str_radix(&player_color_str[1..3], 16).unwrap_or(255),
-                            u8::from_str_radix(&player_color_str[3..5], 16).unwrap_or(255),
-                            u8::from_str_radix(&player_color_str[5..7], 16).unwrap_or(255),
-                        )
-                    }
+                    CellState::Neutral => palette.neutral,
+                    CellState::Owned(player_id) => player_color(&state.players.get(&player_id).unwrap().color),
                 };
                 
                 for row in 0..cell_height {
@@ -224,7 +268,7 @@ impl Widget for GameWidget<'_> {
             let screen_x = area.x + (player.x * cell_width) as u16 + (cell_width / 2) as u16;
             let screen_y = area.y + (player.y * cell_height) as u16 + (cell_height / 2) as u16;
             if screen_x < area.right() && screen_y < area.bottom() {
-                buf[(screen_x, screen_y)].set_symbol(symbol).set_fg(Color::White).set_bg(Color::Black);
+                buf[(screen_x, screen_y)].set_symbol(symbol).set_fg(palette.marker_fg).set_bg(palette.marker_bg);
             }
         }
     }
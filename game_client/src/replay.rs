@@ -0,0 +1,186 @@
+use std::process::ExitCode;
+use std::time::{Duration, Instant};
+
+use crossterm::event::{self, Event, KeyCode, KeyEventKind};
+use game_kernel::*;
+use ratatui::{
+    prelude::*,
+    widgets::{Block, Borders, Gauge, Paragraph},
+};
+
+use crate::api::Api;
+use crate::config::{Config, Palette};
+use crate::{init_terminal, player_color, restore_terminal, GameWidget};
+
+// ===================================================================================
+// CONSTANTES DO REPLAY
+// ===================================================================================
+
+const FRAME_INTERVAL: Duration = Duration::from_millis(400);
+const INPUT_POLL_INTERVAL: Duration = Duration::from_millis(30);
+const SPEEDS: [f64; 6] = [0.25, 0.5, 1.0, 2.0, 4.0, 8.0];
+const DEFAULT_SPEED_INDEX: usize = 2;
+
+// ===================================================================================
+// CARREGAMENTO DO REPLAY
+// ===================================================================================
+
+async fn load_replay(api: &Api, source: &str) -> Result<ReplayExport, Box<dyn std::error::Error>> {
+    match source.parse::<RoomId>() {
+        Ok(room_id) => Ok(api.fetch_replay(room_id).await?),
+        Err(_) => Ok(serde_json::from_slice(&std::fs::read(source)?)?),
+    }
+}
+
+pub async fn run_replay(api: &Api, config: &Config, source: &str) -> Result<ExitCode, Box<dyn std::error::Error>> {
+    let export = match load_replay(api, source).await {
+        Ok(export) => export,
+        Err(e) => {
+            eprintln!("Não foi possível carregar o replay: {}", e);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+    let states = match export.replay() {
+        Ok(states) => states,
+        Err(e) => {
+            eprintln!("Replay inválido: {}", e);
+            return Ok(ExitCode::FAILURE);
+        }
+    };
+
+    let mut terminal = init_terminal()?;
+    let mut viewer = ReplayViewer { export, states, position: 0, playing: true, speed_index: DEFAULT_SPEED_INDEX };
+    let mut last_step = Instant::now();
+
+    loop {
+        terminal.draw(|frame| viewer.render(frame, &config.palette))?;
+
+        if viewer.playing && last_step.elapsed() >= viewer.frame_interval() {
+            viewer.step(1);
+            last_step = Instant::now();
+            if viewer.at_end() {
+                viewer.playing = false;
+            }
+        }
+
+        if event::poll(INPUT_POLL_INTERVAL)?
+            && let Event::Key(key) = event::read()?
+            && key.kind == KeyEventKind::Press
+        {
+            match key.code {
+                KeyCode::Char('q') => break,
+                KeyCode::Char(' ') => {
+                    if viewer.at_end() {
+                        viewer.position = 0;
+                    }
+                    viewer.playing = !viewer.playing;
+                    last_step = Instant::now();
+                }
+                KeyCode::Right | KeyCode::Char('l') => viewer.step(1),
+                KeyCode::Left | KeyCode::Char('h') => viewer.step(-1),
+                KeyCode::Home | KeyCode::Char('g') => viewer.position = 0,
+                KeyCode::End | KeyCode::Char('G') => viewer.position = viewer.last_position(),
+                KeyCode::Char('+') | KeyCode::Char('=') => {
+                    viewer.speed_index = (viewer.speed_index + 1).min(SPEEDS.len() - 1);
+                }
+                KeyCode::Char('-') => viewer.speed_index = viewer.speed_index.saturating_sub(1),
+                _ => {}
+            }
+        }
+    }
+
+    restore_terminal(&mut terminal)?;
+    Ok(ExitCode::SUCCESS)
+}
+
+// ===================================================================================
+// VISUALIZADOR
+// ===================================================================================
+
+struct ReplayViewer {
+    export: ReplayExport,
+    states: Vec<GameState>,
+    position: usize,
+    playing: bool,
+    speed_index: usize,
+}
+
+impl ReplayViewer {
+    fn last_position(&self) -> usize {
+        self.states.len() - 1
+    }
+
+    fn at_end(&self) -> bool {
+        self.position == self.last_position()
+    }
+
+    fn step(&mut self, offset: isize) {
+        self.position = self.position.saturating_add_signed(offset).min(self.last_position());
+    }
+
+    fn frame_interval(&self) -> Duration {
+        FRAME_INTERVAL.div_f64(SPEEDS[self.speed_index])
+    }
+
+    fn current_event(&self) -> Option<&GameEvent> {
+        self.position.checked_sub(1).and_then(|index| self.export.events.get(index))
+    }
+
+    fn render(&self, frame: &mut Frame, palette: &Palette) {
+        let state = &self.states[self.position];
+        let layout = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Min(0),
+            Constraint::Length(3),
+            Constraint::Length(1),
+            Constraint::Length(1),
+        ])
+        .split(frame.area());
+
+        let playback = if self.playing { "▶" } else { "⏸" };
+        let description = self.current_event().map_or("Início da partida".to_string(), |event| describe_event(&event.kind));
+        let status_widget = Paragraph::new(format!("{} {}x  {}", playback, SPEEDS[self.speed_index], description))
+            .style(Style::default().fg(palette.status))
+            .alignment(Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title(format!("Replay da sala {}", self.export.room_id)));
+        frame.render_widget(status_widget, layout[0]);
+
+        let game_block = Block::default().borders(Borders::ALL).title("Mapa");
+        let game_area = game_block.inner(layout[1]);
+        frame.render_widget(game_block, layout[1]);
+        frame.render_widget(GameWidget { state, my_id: 0, palette }, game_area);
+
+        let total = self.last_position();
+        let ratio = if total == 0 { 1.0 } else { self.position as f64 / total as f64 };
+        let scrubber = Gauge::default()
+            .block(Block::default().borders(Borders::ALL).title("Lance"))
+            .gauge_style(Style::default().fg(Color::Cyan))
+            .ratio(ratio)
+            .label(format!("{}/{}", self.position, total));
+        frame.render_widget(scrubber, layout[2]);
+
+        let mut players: Vec<_> = state.players.values().collect();
+        players.sort_by_key(|p| p.id);
+        let scores: Vec<Span> = players
+            .into_iter()
+            .map(|p| Span::styled(format!(" Jogador {}: {} ", p.id, state.score_of(p.id)), Style::default().fg(player_color(&p.color))))
+            .collect();
+        frame.render_widget(Paragraph::new(Line::from(scores)), layout[3]);
+
+        let help = "espaço: tocar/pausar  ←/→: lance anterior/próximo  +/-: velocidade  g/G: início/fim  q: sair";
+        frame.render_widget(Paragraph::new(help).style(Style::default().fg(Color::DarkGray)), layout[4]);
+    }
+}
+
+fn describe_event(event: &GameEventKind) -> String {
+    match event {
+        GameEventKind::Join { player_id, x, y } => format!("Jogador {} entrou em ({}, {})", player_id, x, y),
+        GameEventKind::Move { player_id, direction, x, y } => {
+            format!("Jogador {} moveu {} para ({}, {})", player_id, direction, x, y)
+        }
+        GameEventKind::Status { status: GameStatus::WaitingForPlayers } => "Aguardando jogadores".to_string(),
+        GameEventKind::Status { status: GameStatus::InProgress } => "Partida iniciada".to_string(),
+        GameEventKind::Status { status: GameStatus::Finished } => "Fim de jogo".to_string(),
+        GameEventKind::Restart { .. } => "Partida reiniciada".to_string(),
+    }
+}
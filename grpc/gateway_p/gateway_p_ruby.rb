@@ -11,8 +11,8 @@ require 'service_b/game_state_services_pb'
 require 'service_a/game_movement_pb'
 require 'service_a/game_movement_services_pb'
 
-GAMEMOVE_GRPC_ADDRESS  = 'service-a-service:50052'
-GAMESTATE_GRPC_ADDRESS = 'service-b-service:50051'
+GAMEMOVE_GRPC_ADDRESS  = ENV.fetch('GAMEMOVE_GRPC_ADDRESS', 'service-a-service:50052')
+GAMESTATE_GRPC_ADDRESS = ENV.fetch('GAMESTATE_GRPC_ADDRESS', 'service-b-service:50051')
 
 $game_state_stub = Gamestate::GameStateService::Stub.new(
   GAMESTATE_GRPC_ADDRESS,
@@ -33,6 +33,12 @@ class GameHTTPBridge < Sinatra::Base
     content_type 'application/json'
   end
 
+  # O token de sessão devolvido pelo JoinGame segue como metadado gRPC
+  def session_metadata
+    auth = request.env['HTTP_AUTHORIZATION']
+    auth ? { 'authorization' => auth } : {}
+  end
+
   post '/game/join' do
     resp = $game_state_stub.join_game(Gamestate::JoinGameRequest.new)
     Google::Protobuf.encode_json(resp, emit_defaults: true)
@@ -82,7 +88,7 @@ class GameHTTPBridge < Sinatra::Base
       direction: direction
     )
     
-    resp = $game_move_stub.execute_move(req)
+    resp = $game_move_stub.execute_move(req, metadata: session_metadata)
     Google::Protobuf.encode_json(resp, emit_defaults: true)
   rescue => e
     status 500
@@ -107,7 +113,7 @@ class GameHTTPBridge < Sinatra::Base
 
   post '/game/restart' do
     begin
-      resp = $game_state_stub.restart_game(Gamestate::RestartGameRequest.new)
+      resp = $game_state_stub.restart_game(Gamestate::RestartGameRequest.new, metadata: session_metadata)
       Google::Protobuf.encode_json(resp, emit_defaults: true)
     rescue => e
       status 500
@@ -5,7 +5,7 @@
 require 'google/protobuf'
 
 
-descriptor_data = "\n\x1dservice_a/game_movement.proto\x12\x0cgamemovement\"9\n\x06Player\x12\n\n\x02id\x18\x01 \x01(\x05\x12\t\n\x01x\x18\x02 \x01(\x05\x12\t\n\x01y\x18\x03 \x01(\x05\x12\r\n\x05\x63olor\x18\x04 \x01(\t\"@\n\x04\x43\x65ll\x12&\n\x05state\x18\x01 \x01(\x0e\x32\x17.gamemovement.CellState\x12\x10\n\x08owner_id\x18\x02 \x01(\x05\",\n\x07GridRow\x12!\n\x05\x63\x65lls\x18\x01 \x03(\x0b\x32\x12.gamemovement.Cell\"+\n\x04Grid\x12#\n\x04rows\x18\x01 \x03(\x0b\x32\x15.gamemovement.GridRow\"\x89\x02\n\tGameState\x12(\n\x06status\x18\x01 \x01(\x0e\x32\x18.gamemovement.GameStatus\x12\r\n\x05width\x18\x02 \x01(\x05\x12\x0e\n\x06height\x18\x03 \x01(\x05\x12 \n\x04grid\x18\x04 \x01(\x0b\x32\x12.gamemovement.Grid\x12\x35\n\x07players\x18\x05 \x03(\x0b\x32$.gamemovement.GameState.PlayersEntry\x12\x14\n\x0c\x63urrent_turn\x18\x06 \x01(\x05\x1a\x44\n\x0cPlayersEntry\x12\x0b\n\x03key\x18\x01 \x01(\x05\x12#\n\x05value\x18\x02 \x01(\x0b\x32\x14.gamemovement.Player:\x02\x38\x01\"\x84\x01\n\x13ValidateMoveRequest\x12.\n\rcurrent_state\x18\x01 \x01(\x0b\x32\x17.gamemovement.GameState\x12\x11\n\tplayer_id\x18\x02 \x01(\x05\x12*\n\tdirection\x18\x03 \x01(\x0e\x32\x17.gamemovement.Direction\"\x83\x01\n\x12\x45xecuteMoveRequest\x12.\n\rcurrent_state\x18\x01 \x01(\x0b\x32\x17.gamemovement.GameState\x12\x11\n\tplayer_id\x18\x02 \x01(\x05\x12*\n\tdirection\x18\x03 \x01(\x0e\x32\x17.gamemovement.Direction\"Y\n\x14GetValidMovesRequest\x12.\n\rcurrent_state\x18\x01 \x01(\x0b\x32\x17.gamemovement.GameState\x12\x11\n\tplayer_id\x18\x02 \x01(\x05\"7\n\x14ValidateMoveResponse\x12\x10\n\x08is_valid\x18\x01 \x01(\x08\x12\r\n\x05\x65rror\x18\x02 \x01(\t\"g\n\x13\x45xecuteMoveResponse\x12*\n\tnew_state\x18\x01 \x01(\x0b\x32\x17.gamemovement.GameState\x12\x15\n\rgame_finished\x18\x02 \x01(\x08\x12\r\n\x05\x65rror\x18\x03 \x01(\t\"e\n\tValidMove\x12*\n\tdirection\x18\x01 \x01(\x0e\x32\x17.gamemovement.Direction\x12\x15\n\rdestination_x\x18\x02 \x01(\x05\x12\x15\n\rdestination_y\x18\x03 \x01(\x05\"E\n\x15GetValidMovesResponse\x12,\n\x0bvalid_moves\x18\x01 \x03(\x0b\x32\x17.gamemovement.ValidMove*D\n\nGameStatus\x12\x17\n\x13WAITING_FOR_PLAYERS\x10\x00\x12\x0f\n\x0bIN_PROGRESS\x10\x01\x12\x0c\n\x08\x46INISHED\x10\x02*#\n\tCellState\x12\x0b\n\x07NEUTRAL\x10\x00\x12\t\n\x05OWNED\x10\x01*2\n\tDirection\x12\x06\n\x02UP\x10\x00\x12\x08\n\x04\x44OWN\x10\x01\x12\x08\n\x04LEFT\x10\x02\x12\t\n\x05RIGHT\x10\x03\x32\x96\x02\n\x0fGameMoveService\x12U\n\x0cValidateMove\x12!.gamemovement.ValidateMoveRequest\x1a\".gamemovement.ValidateMoveResponse\x12R\n\x0b\x45xecuteMove\x12 .gamemovement.ExecuteMoveRequest\x1a!.gamemovement.ExecuteMoveResponse\x12X\n\rGetValidMoves\x12\".gamemovement.GetValidMovesRequest\x1a#.gamemovement.GetValidMovesResponseB\tZ\x07./protob\x06proto3"
+descriptor_data = "\n\x1dservice_a/game_movement.proto\x12\x0cgamemovement\"9\n\x06Player\x12\n\n\x02id\x18\x01 \x01(\x05\x12\t\n\x01x\x18\x02 \x01(\x05\x12\t\n\x01y\x18\x03 \x01(\x05\x12\r\n\x05\x63olor\x18\x04 \x01(\t\"@\n\x04\x43\x65ll\x12&\n\x05state\x18\x01 \x01(\x0e\x32\x17.gamemovement.CellState\x12\x10\n\x08owner_id\x18\x02 \x01(\x05\",\n\x07GridRow\x12!\n\x05\x63\x65lls\x18\x01 \x03(\x0b\x32\x12.gamemovement.Cell\"+\n\x04Grid\x12#\n\x04rows\x18\x01 \x03(\x0b\x32\x15.gamemovement.GridRow\"\xf3\x02\n\tGameState\x12(\n\x06status\x18\x01 \x01(\x0e\x32\x18.gamemovement.GameStatus\x12\r\n\x05width\x18\x02 \x01(\x05\x12\x0e\n\x06height\x18\x03 \x01(\x05\x12 \n\x04grid\x18\x04 \x01(\x0b\x32\x12.gamemovement.Grid\x12\x35\n\x07players\x18\x05 \x03(\x0b\x32$.gamemovement.GameState.PlayersEntry\x12\x14\n\x0c\x63urrent_turn\x18\x06 \x01(\x05\x12\x13\n\x0bmin_players\x18\x07 \x01(\x05\x12\x13\n\x0bmax_players\x18\x08 \x01(\x05\x12\x14\n\x07version\x18\t \x01(\x04H\x00\x88\x01\x01\x12\x12\n\x05\x65poch\x18\n \x01(\rH\x01\x88\x01\x01\x1a\x44\n\x0cPlayersEntry\x12\x0b\n\x03key\x18\x01 \x01(\x05\x12#\n\x05value\x18\x02 \x01(\x0b\x32\x14.gamemovement.Player:\x02\x38\x01\x42\n\n\x08_versionB\x08\n\x06_epoch\"\x84\x01\n\x13ValidateMoveRequest\x12.\n\rcurrent_state\x18\x01 \x01(\x0b\x32\x17.gamemovement.GameState\x12\x11\n\tplayer_id\x18\x02 \x01(\x05\x12*\n\tdirection\x18\x03 \x01(\x0e\x32\x17.gamemovement.Direction\"\x83\x01\n\x12\x45xecuteMoveRequest\x12.\n\rcurrent_state\x18\x01 \x01(\x0b\x32\x17.gamemovement.GameState\x12\x11\n\tplayer_id\x18\x02 \x01(\x05\x12*\n\tdirection\x18\x03 \x01(\x0e\x32\x17.gamemovement.Direction\"Y\n\x14GetValidMovesRequest\x12.\n\rcurrent_state\x18\x01 \x01(\x0b\x32\x17.gamemovement.GameState\x12\x11\n\tplayer_id\x18\x02 \x01(\x05\"7\n\x14ValidateMoveResponse\x12\x10\n\x08is_valid\x18\x01 \x01(\x08\x12\r\n\x05\x65rror\x18\x02 \x01(\t\"g\n\x13\x45xecuteMoveResponse\x12*\n\tnew_state\x18\x01 \x01(\x0b\x32\x17.gamemovement.GameState\x12\x15\n\rgame_finished\x18\x02 \x01(\x08\x12\r\n\x05\x65rror\x18\x03 \x01(\t\"e\n\tValidMove\x12*\n\tdirection\x18\x01 \x01(\x0e\x32\x17.gamemovement.Direction\x12\x15\n\rdestination_x\x18\x02 \x01(\x05\x12\x15\n\rdestination_y\x18\x03 \x01(\x05\"E\n\x15GetValidMovesResponse\x12,\n\x0bvalid_moves\x18\x01 \x03(\x0b\x32\x17.gamemovement.ValidMove*D\n\nGameStatus\x12\x17\n\x13WAITING_FOR_PLAYERS\x10\x00\x12\x0f\n\x0bIN_PROGRESS\x10\x01\x12\x0c\n\x08\x46INISHED\x10\x02*#\n\tCellState\x12\x0b\n\x07NEUTRAL\x10\x00\x12\t\n\x05OWNED\x10\x01*2\n\tDirection\x12\x06\n\x02UP\x10\x00\x12\x08\n\x04\x44OWN\x10\x01\x12\x08\n\x04LEFT\x10\x02\x12\t\n\x05RIGHT\x10\x03\x32\x96\x02\n\x0fGameMoveService\x12U\n\x0cValidateMove\x12!.gamemovement.ValidateMoveRequest\x1a\".gamemovement.ValidateMoveResponse\x12R\n\x0b\x45xecuteMove\x12 .gamemovement.ExecuteMoveRequest\x1a!.gamemovement.ExecuteMoveResponse\x12X\n\rGetValidMoves\x12\".gamemovement.GetValidMovesRequest\x1a#.gamemovement.GetValidMovesResponseB\tZ\x07./protob\x06proto3"
 
 pool = ::Google::Protobuf::DescriptorPool.generated_pool
 pool.add_serialized_file(descriptor_data)
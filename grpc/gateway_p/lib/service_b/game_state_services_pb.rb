@@ -17,6 +17,7 @@ module Gamestate
       rpc :JoinGame, ::Gamestate::JoinGameRequest, ::Gamestate::JoinGameResponse
       rpc :GetGameState, ::Gamestate::GetGameStateRequest, ::Gamestate::GameStateResponse
       rpc :UpdateGameState, ::Gamestate::UpdateGameStateRequest, ::Gamestate::UpdateGameStateResponse
+      rpc :RestartGame, ::Gamestate::RestartGameRequest, ::Gamestate::RestartGameResponse
     end
 
     Stub = Service.rpc_stub_class
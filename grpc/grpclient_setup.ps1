@@ -1,5 +1,8 @@
 $ErrorActionPreference = "Stop"
 
+# O cliente WEB é o mesmo da versão REST; o transporte é escolhido pela URL (?transporte=grpc)
+Set-Location ../rest
+
 if (Test-Path "wasm_game_client/www/pkg") {
     Remove-Item -Recurse -Force "wasm_game_client/www/pkg"
 }
@@ -12,4 +15,6 @@ wasm-bindgen target/wasm32-unknown-unknown/release/wasm_game_client.wasm `
 
 Set-Location wasm_game_client/www
 
+Write-Host "Acesse http://localhost:8080/?transporte=grpc"
+
 python -m http.server 8080
\ No newline at end of file
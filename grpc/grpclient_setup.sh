@@ -1,6 +1,9 @@
 #!/bin/bash
 set -e
 
+# O cliente WEB é o mesmo da versão REST; o transporte é escolhido pela URL (?transporte=grpc)
+cd ../rest
+
 rm -rf wasm_game_client/www/pkg
 
 cargo build --target wasm32-unknown-unknown --release --package wasm_game_client
@@ -9,10 +12,8 @@ wasm-bindgen target/wasm32-unknown-unknown/release/wasm_game_client.wasm \
   --out-dir wasm_game_client/www/pkg \
   --target web
 
-MINIKUBE_IP=$(minikube ip)
-
-echo "export const MINIKUBE_IP = '$MINIKUBE_IP';" > wasm_game_client/www/config.js
-
 cd wasm_game_client/www
 
+echo "Acesse http://localhost:8080/?transporte=grpc"
+
 python3 -m http.server 8080
\ No newline at end of file
@@ -5,6 +5,7 @@ import (
 	"fmt"
 	"log"
 	"net"
+	"os"
 
 	gamemovementpb "service_a/proto/a"
 	gamestatepb "service_a/proto/b"
@@ -12,11 +13,20 @@ import (
 	"google.golang.org/grpc"
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/credentials/insecure"
+	"google.golang.org/grpc/metadata"
 	"google.golang.org/grpc/reflection"
 	"google.golang.org/grpc/status"
 )
 
-const serviceBAddr = "service-b-service:50051"
+const defaultServiceBAddr = "service-b-service:50051"
+
+// SERVICE_B_ADDR permite apontar para outro GameStateService (por exemplo, o servico_b em Rust)
+func serviceBAddr() string {
+	if addr := os.Getenv("SERVICE_B_ADDR"); addr != "" {
+		return addr
+	}
+	return defaultServiceBAddr
+}
 
 type GameMoveService struct {
 	gamemovementpb.UnimplementedGameMoveServiceServer
@@ -24,7 +34,7 @@ type GameMoveService struct {
 }
 
 func NewGameMoveService() (*GameMoveService, error) {
-	conn, err := grpc.Dial(serviceBAddr, grpc.WithTransportCredentials(insecure.NewCredentials()))
+	conn, err := grpc.Dial(serviceBAddr(), grpc.WithTransportCredentials(insecure.NewCredentials()))
 	if err != nil {
 		return nil, fmt.Errorf("falha ao conectar ao Service B: %w", err)
 	}
@@ -115,9 +125,23 @@ func (s *GameMoveService) ExecuteMove(ctx context.Context, req *gamemovementpb.E
 		state.Status = gamestatepb.GameStatus_FINISHED
 	}
 
+	// O token de sessão do jogador segue para o Serviço B, que autentica o UpdateGameState
+	if auth := metadata.ValueFromIncomingContext(ctx, "authorization"); len(auth) > 0 {
+		ctx = metadata.AppendToOutgoingContext(ctx, "authorization", auth[0])
+	}
 	_, err = s.stateClient.UpdateGameState(ctx, &gamestatepb.UpdateGameStateRequest{
 		State: state,
 	})
+	if status.Code(err) == codes.Unauthenticated {
+		return &gamemovementpb.ExecuteMoveResponse{
+			Error: "Sessão inválida; entre no jogo de novo",
+		}, status.Error(codes.Unauthenticated, "sessão inválida")
+	}
+	if status.Code(err) == codes.Aborted {
+		return &gamemovementpb.ExecuteMoveResponse{
+			Error: "O estado mudou durante o movimento; tente de novo",
+		}, status.Error(codes.Aborted, "estado desatualizado")
+	}
 	if err != nil {
 		log.Printf("[Service A] Erro ao atualizar estado: %v", err)
 		return &gamemovementpb.ExecuteMoveResponse{
@@ -237,6 +261,10 @@ func convertGameState(state *gamestatepb.GameState) *gamemovementpb.GameState {
 		Grid:        &gamemovementpb.Grid{Rows: gridRows},
 		Players:     players,
 		CurrentTurn: 0,
+		MinPlayers:  state.MinPlayers,
+		MaxPlayers:  state.MaxPlayers,
+		Version:     state.Version,
+		Epoch:       state.Epoch,
 	}
 }
 
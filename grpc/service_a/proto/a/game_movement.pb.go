@@ -386,6 +386,10 @@ type GameState struct {
 	Grid          *Grid                  `protobuf:"bytes,4,opt,name=grid,proto3" json:"grid,omitempty"`
 	Players       map[int32]*Player      `protobuf:"bytes,5,rep,name=players,proto3" json:"players,omitempty" protobuf_key:"varint,1,opt,name=key" protobuf_val:"bytes,2,opt,name=value"`
 	CurrentTurn   int32                  `protobuf:"varint,6,opt,name=current_turn,json=currentTurn,proto3" json:"current_turn,omitempty"`
+	MinPlayers    int32                  `protobuf:"varint,7,opt,name=min_players,json=minPlayers,proto3" json:"min_players,omitempty"` // jogadores necessários para iniciar
+	MaxPlayers    int32                  `protobuf:"varint,8,opt,name=max_players,json=maxPlayers,proto3" json:"max_players,omitempty"` // capacidade da sala
+	Version       *uint64                `protobuf:"varint,9,opt,name=version,proto3,oneof" json:"version,omitempty"`                   // versão em que o estado foi lido
+	Epoch         *uint32                `protobuf:"varint,10,opt,name=epoch,proto3,oneof" json:"epoch,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -462,6 +466,34 @@ func (x *GameState) GetCurrentTurn() int32 {
 	return 0
 }
 
+func (x *GameState) GetMinPlayers() int32 {
+	if x != nil {
+		return x.MinPlayers
+	}
+	return 0
+}
+
+func (x *GameState) GetMaxPlayers() int32 {
+	if x != nil {
+		return x.MaxPlayers
+	}
+	return 0
+}
+
+func (x *GameState) GetVersion() uint64 {
+	if x != nil && x.Version != nil {
+		return *x.Version
+	}
+	return 0
+}
+
+func (x *GameState) GetEpoch() uint32 {
+	if x != nil && x.Epoch != nil {
+		return *x.Epoch
+	}
+	return 0
+}
+
 // Request/Response messages
 type ValidateMoveRequest struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
@@ -867,17 +899,27 @@ const file_game_movement_proto_rawDesc = "" +
 	"\aGridRow\x12(\n" +
 	"\x05cells\x18\x01 \x03(\v2\x12.gamemovement.CellR\x05cells\"1\n" +
 	"\x04Grid\x12)\n" +
-	"\x04rows\x18\x01 \x03(\v2\x15.gamemovement.GridRowR\x04rows\"\xc8\x02\n" +
+	"\x04rows\x18\x01 \x03(\v2\x15.gamemovement.GridRowR\x04rows\"\xda\x03\n" +
 	"\tGameState\x120\n" +
 	"\x06status\x18\x01 \x01(\x0e2\x18.gamemovement.GameStatusR\x06status\x12\x14\n" +
 	"\x05width\x18\x02 \x01(\x05R\x05width\x12\x16\n" +
 	"\x06height\x18\x03 \x01(\x05R\x06height\x12&\n" +
 	"\x04grid\x18\x04 \x01(\v2\x12.gamemovement.GridR\x04grid\x12>\n" +
 	"\aplayers\x18\x05 \x03(\v2$.gamemovement.GameState.PlayersEntryR\aplayers\x12!\n" +
-	"\fcurrent_turn\x18\x06 \x01(\x05R\vcurrentTurn\x1aP\n" +
+	"\fcurrent_turn\x18\x06 \x01(\x05R\vcurrentTurn\x12\x1f\n" +
+	"\vmin_players\x18\a \x01(\x05R\n" +
+	"minPlayers\x12\x1f\n" +
+	"\vmax_players\x18\b \x01(\x05R\n" +
+	"maxPlayers\x12\x1d\n" +
+	"\aversion\x18\t \x01(\x04H\x00R\aversion\x88\x01\x01\x12\x19\n" +
+	"\x05epoch\x18\n" +
+	" \x01(\rH\x01R\x05epoch\x88\x01\x01\x1aP\n" +
 	"\fPlayersEntry\x12\x10\n" +
 	"\x03key\x18\x01 \x01(\x05R\x03key\x12*\n" +
-	"\x05value\x18\x02 \x01(\v2\x14.gamemovement.PlayerR\x05value:\x028\x01\"\xa7\x01\n" +
+	"\x05value\x18\x02 \x01(\v2\x14.gamemovement.PlayerR\x05value:\x028\x01B\n" +
+	"\n" +
+	"\b_versionB\b\n" +
+	"\x06_epoch\"\xa7\x01\n" +
 	"\x13ValidateMoveRequest\x12<\n" +
 	"\rcurrent_state\x18\x01 \x01(\v2\x17.gamemovement.GameStateR\fcurrentState\x12\x1b\n" +
 	"\tplayer_id\x18\x02 \x01(\x05R\bplayerId\x125\n" +
@@ -987,6 +1029,7 @@ func file_game_movement_proto_init() {
 	if File_game_movement_proto != nil {
 		return
 	}
+	file_game_movement_proto_msgTypes[4].OneofWrappers = []any{}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
 		File: protoimpl.DescBuilder{
@@ -333,6 +333,10 @@ type GameState struct {
 	Height        int32                  `protobuf:"varint,3,opt,name=height,proto3" json:"height,omitempty"`
 	Grid          *Grid                  `protobuf:"bytes,4,opt,name=grid,proto3" json:"grid,omitempty"`
 	Players       map[int32]*Player      `protobuf:"bytes,5,rep,name=players,proto3" json:"players,omitempty" protobuf_key:"varint,1,opt,name=key" protobuf_val:"bytes,2,opt,name=value"`
+	MinPlayers    int32                  `protobuf:"varint,6,opt,name=min_players,json=minPlayers,proto3" json:"min_players,omitempty"` // jogadores necessários para iniciar
+	MaxPlayers    int32                  `protobuf:"varint,7,opt,name=max_players,json=maxPlayers,proto3" json:"max_players,omitempty"` // capacidade da sala
+	Version       *uint64                `protobuf:"varint,8,opt,name=version,proto3,oneof" json:"version,omitempty"`                   // versão em que o estado foi lido; o UpdateGameState recusa versões antigas
+	Epoch         *uint32                `protobuf:"varint,9,opt,name=epoch,proto3,oneof" json:"epoch,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -402,6 +406,34 @@ func (x *GameState) GetPlayers() map[int32]*Player {
 	return nil
 }
 
+func (x *GameState) GetMinPlayers() int32 {
+	if x != nil {
+		return x.MinPlayers
+	}
+	return 0
+}
+
+func (x *GameState) GetMaxPlayers() int32 {
+	if x != nil {
+		return x.MaxPlayers
+	}
+	return 0
+}
+
+func (x *GameState) GetVersion() uint64 {
+	if x != nil && x.Version != nil {
+		return *x.Version
+	}
+	return 0
+}
+
+func (x *GameState) GetEpoch() uint32 {
+	if x != nil && x.Epoch != nil {
+		return *x.Epoch
+	}
+	return 0
+}
+
 // Request/Response messages
 type JoinGameRequest struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
@@ -443,6 +475,7 @@ type JoinGameResponse struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	Player        *Player                `protobuf:"bytes,1,opt,name=player,proto3" json:"player,omitempty"`
 	Error         string                 `protobuf:"bytes,2,opt,name=error,proto3" json:"error,omitempty"`
+	Token         string                 `protobuf:"bytes,3,opt,name=token,proto3" json:"token,omitempty"` // token de sessão exigido pelo UpdateGameState e pelo RestartGame; vazio se o servidor não autentica
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -491,6 +524,13 @@ func (x *JoinGameResponse) GetError() string {
 	return ""
 }
 
+func (x *JoinGameResponse) GetToken() string {
+	if x != nil {
+		return x.Token
+	}
+	return ""
+}
+
 type GetGameStateRequest struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	unknownFields protoimpl.UnknownFields
@@ -659,6 +699,86 @@ func (x *UpdateGameStateResponse) GetSuccess() bool {
 	return false
 }
 
+type RestartGameRequest struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	KeepPlayers   bool                   `protobuf:"varint,1,opt,name=keep_players,json=keepPlayers,proto3" json:"keep_players,omitempty"` // revanche com os mesmos jogadores
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RestartGameRequest) Reset() {
+	*x = RestartGameRequest{}
+	mi := &file_game_state_proto_msgTypes[11]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RestartGameRequest) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RestartGameRequest) ProtoMessage() {}
+
+func (x *RestartGameRequest) ProtoReflect() protoreflect.Message {
+	mi := &file_game_state_proto_msgTypes[11]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RestartGameRequest.ProtoReflect.Descriptor instead.
+func (*RestartGameRequest) Descriptor() ([]byte, []int) {
+	return file_game_state_proto_rawDescGZIP(), []int{11}
+}
+
+func (x *RestartGameRequest) GetKeepPlayers() bool {
+	if x != nil {
+		return x.KeepPlayers
+	}
+	return false
+}
+
+type RestartGameResponse struct {
+	state         protoimpl.MessageState `protogen:"open.v1"`
+	unknownFields protoimpl.UnknownFields
+	sizeCache     protoimpl.SizeCache
+}
+
+func (x *RestartGameResponse) Reset() {
+	*x = RestartGameResponse{}
+	mi := &file_game_state_proto_msgTypes[12]
+	ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+	ms.StoreMessageInfo(mi)
+}
+
+func (x *RestartGameResponse) String() string {
+	return protoimpl.X.MessageStringOf(x)
+}
+
+func (*RestartGameResponse) ProtoMessage() {}
+
+func (x *RestartGameResponse) ProtoReflect() protoreflect.Message {
+	mi := &file_game_state_proto_msgTypes[12]
+	if x != nil {
+		ms := protoimpl.X.MessageStateOf(protoimpl.Pointer(x))
+		if ms.LoadMessageInfo() == nil {
+			ms.StoreMessageInfo(mi)
+		}
+		return ms
+	}
+	return mi.MessageOf(x)
+}
+
+// Deprecated: Use RestartGameResponse.ProtoReflect.Descriptor instead.
+func (*RestartGameResponse) Descriptor() ([]byte, []int) {
+	return file_game_state_proto_rawDescGZIP(), []int{12}
+}
+
 var File_game_state_proto protoreflect.FileDescriptor
 
 const file_game_state_proto_rawDesc = "" +
@@ -675,27 +795,40 @@ const file_game_state_proto_rawDesc = "" +
 	"\x04Grid\x12&\n" +
 	"\x04rows\x18\x01 \x03(\v2\x12.gamestate.GridRowR\x04rows\"0\n" +
 	"\aGridRow\x12%\n" +
-	"\x05cells\x18\x01 \x03(\v2\x0f.gamestate.CellR\x05cells\"\x99\x02\n" +
+	"\x05cells\x18\x01 \x03(\v2\x0f.gamestate.CellR\x05cells\"\xab\x03\n" +
 	"\tGameState\x12-\n" +
 	"\x06status\x18\x01 \x01(\x0e2\x15.gamestate.GameStatusR\x06status\x12\x14\n" +
 	"\x05width\x18\x02 \x01(\x05R\x05width\x12\x16\n" +
 	"\x06height\x18\x03 \x01(\x05R\x06height\x12#\n" +
 	"\x04grid\x18\x04 \x01(\v2\x0f.gamestate.GridR\x04grid\x12;\n" +
-	"\aplayers\x18\x05 \x03(\v2!.gamestate.GameState.PlayersEntryR\aplayers\x1aM\n" +
+	"\aplayers\x18\x05 \x03(\v2!.gamestate.GameState.PlayersEntryR\aplayers\x12\x1f\n" +
+	"\vmin_players\x18\x06 \x01(\x05R\n" +
+	"minPlayers\x12\x1f\n" +
+	"\vmax_players\x18\a \x01(\x05R\n" +
+	"maxPlayers\x12\x1d\n" +
+	"\aversion\x18\b \x01(\x04H\x00R\aversion\x88\x01\x01\x12\x19\n" +
+	"\x05epoch\x18\t \x01(\rH\x01R\x05epoch\x88\x01\x01\x1aM\n" +
 	"\fPlayersEntry\x12\x10\n" +
 	"\x03key\x18\x01 \x01(\x05R\x03key\x12'\n" +
-	"\x05value\x18\x02 \x01(\v2\x11.gamestate.PlayerR\x05value:\x028\x01\"\x11\n" +
-	"\x0fJoinGameRequest\"S\n" +
+	"\x05value\x18\x02 \x01(\v2\x11.gamestate.PlayerR\x05value:\x028\x01B\n" +
+	"\n" +
+	"\b_versionB\b\n" +
+	"\x06_epoch\"\x11\n" +
+	"\x0fJoinGameRequest\"i\n" +
 	"\x10JoinGameResponse\x12)\n" +
 	"\x06player\x18\x01 \x01(\v2\x11.gamestate.PlayerR\x06player\x12\x14\n" +
-	"\x05error\x18\x02 \x01(\tR\x05error\"\x15\n" +
+	"\x05error\x18\x02 \x01(\tR\x05error\x12\x14\n" +
+	"\x05token\x18\x03 \x01(\tR\x05token\"\x15\n" +
 	"\x13GetGameStateRequest\"?\n" +
 	"\x11GameStateResponse\x12*\n" +
 	"\x05state\x18\x01 \x01(\v2\x14.gamestate.GameStateR\x05state\"D\n" +
 	"\x16UpdateGameStateRequest\x12*\n" +
 	"\x05state\x18\x01 \x01(\v2\x14.gamestate.GameStateR\x05state\"3\n" +
 	"\x17UpdateGameStateResponse\x12\x18\n" +
-	"\asuccess\x18\x01 \x01(\bR\asuccess*D\n" +
+	"\asuccess\x18\x01 \x01(\bR\asuccess\"7\n" +
+	"\x12RestartGameRequest\x12!\n" +
+	"\fkeep_players\x18\x01 \x01(\bR\vkeepPlayers\"\x15\n" +
+	"\x13RestartGameResponse*D\n" +
 	"\n" +
 	"GameStatus\x12\x17\n" +
 	"\x13WAITING_FOR_PLAYERS\x10\x00\x12\x0f\n" +
@@ -703,11 +836,12 @@ const file_game_state_proto_rawDesc = "" +
 	"\bFINISHED\x10\x02*#\n" +
 	"\tCellState\x12\v\n" +
 	"\aNEUTRAL\x10\x00\x12\t\n" +
-	"\x05OWNED\x10\x012\xff\x01\n" +
+	"\x05OWNED\x10\x012\xcd\x02\n" +
 	"\x10GameStateService\x12C\n" +
 	"\bJoinGame\x12\x1a.gamestate.JoinGameRequest\x1a\x1b.gamestate.JoinGameResponse\x12L\n" +
 	"\fGetGameState\x12\x1e.gamestate.GetGameStateRequest\x1a\x1c.gamestate.GameStateResponse\x12X\n" +
-	"\x0fUpdateGameState\x12!.gamestate.UpdateGameStateRequest\x1a\".gamestate.UpdateGameStateResponseB\tZ\a./protob\x06proto3"
+	"\x0fUpdateGameState\x12!.gamestate.UpdateGameStateRequest\x1a\".gamestate.UpdateGameStateResponse\x12L\n" +
+	"\vRestartGame\x12\x1d.gamestate.RestartGameRequest\x1a\x1e.gamestate.RestartGameResponseB\tZ\a./protob\x06proto3"
 
 var (
 	file_game_state_proto_rawDescOnce sync.Once
@@ -722,7 +856,7 @@ func file_game_state_proto_rawDescGZIP() []byte {
 }
 
 var file_game_state_proto_enumTypes = make([]protoimpl.EnumInfo, 2)
-var file_game_state_proto_msgTypes = make([]protoimpl.MessageInfo, 12)
+var file_game_state_proto_msgTypes = make([]protoimpl.MessageInfo, 14)
 var file_game_state_proto_goTypes = []any{
 	(GameStatus)(0),                 // 0: gamestate.GameStatus
 	(CellState)(0),                  // 1: gamestate.CellState
@@ -737,7 +871,9 @@ var file_game_state_proto_goTypes = []any{
 	(*GameStateResponse)(nil),       // 10: gamestate.GameStateResponse
 	(*UpdateGameStateRequest)(nil),  // 11: gamestate.UpdateGameStateRequest
 	(*UpdateGameStateResponse)(nil), // 12: gamestate.UpdateGameStateResponse
-	nil,                             // 13: gamestate.GameState.PlayersEntry
+	(*RestartGameRequest)(nil),      // 13: gamestate.RestartGameRequest
+	(*RestartGameResponse)(nil),     // 14: gamestate.RestartGameResponse
+	nil,                             // 15: gamestate.GameState.PlayersEntry
 }
 var file_game_state_proto_depIdxs = []int32{
 	1,  // 0: gamestate.Cell.state:type_name -> gamestate.CellState
@@ -745,7 +881,7 @@ var file_game_state_proto_depIdxs = []int32{
 	3,  // 2: gamestate.GridRow.cells:type_name -> gamestate.Cell
 	0,  // 3: gamestate.GameState.status:type_name -> gamestate.GameStatus
 	4,  // 4: gamestate.GameState.grid:type_name -> gamestate.Grid
-	13, // 5: gamestate.GameState.players:type_name -> gamestate.GameState.PlayersEntry
+	15, // 5: gamestate.GameState.players:type_name -> gamestate.GameState.PlayersEntry
 	2,  // 6: gamestate.JoinGameResponse.player:type_name -> gamestate.Player
 	6,  // 7: gamestate.GameStateResponse.state:type_name -> gamestate.GameState
 	6,  // 8: gamestate.UpdateGameStateRequest.state:type_name -> gamestate.GameState
@@ -753,11 +889,13 @@ var file_game_state_proto_depIdxs = []int32{
 	7,  // 10: gamestate.GameStateService.JoinGame:input_type -> gamestate.JoinGameRequest
 	9,  // 11: gamestate.GameStateService.GetGameState:input_type -> gamestate.GetGameStateRequest
 	11, // 12: gamestate.GameStateService.UpdateGameState:input_type -> gamestate.UpdateGameStateRequest
-	8,  // 13: gamestate.GameStateService.JoinGame:output_type -> gamestate.JoinGameResponse
-	10, // 14: gamestate.GameStateService.GetGameState:output_type -> gamestate.GameStateResponse
-	12, // 15: gamestate.GameStateService.UpdateGameState:output_type -> gamestate.UpdateGameStateResponse
-	13, // [13:16] is the sub-list for method output_type
-	10, // [10:13] is the sub-list for method input_type
+	13, // 13: gamestate.GameStateService.RestartGame:input_type -> gamestate.RestartGameRequest
+	8,  // 14: gamestate.GameStateService.JoinGame:output_type -> gamestate.JoinGameResponse
+	10, // 15: gamestate.GameStateService.GetGameState:output_type -> gamestate.GameStateResponse
+	12, // 16: gamestate.GameStateService.UpdateGameState:output_type -> gamestate.UpdateGameStateResponse
+	14, // 17: gamestate.GameStateService.RestartGame:output_type -> gamestate.RestartGameResponse
+	14, // [14:18] is the sub-list for method output_type
+	10, // [10:14] is the sub-list for method input_type
 	10, // [10:10] is the sub-list for extension type_name
 	10, // [10:10] is the sub-list for extension extendee
 	0,  // [0:10] is the sub-list for field type_name
@@ -768,13 +906,14 @@ func file_game_state_proto_init() {
 	if File_game_state_proto != nil {
 		return
 	}
+	file_game_state_proto_msgTypes[4].OneofWrappers = []any{}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
 		File: protoimpl.DescBuilder{
 			GoPackagePath: reflect.TypeOf(x{}).PkgPath(),
 			RawDescriptor: unsafe.Slice(unsafe.StringData(file_game_state_proto_rawDesc), len(file_game_state_proto_rawDesc)),
 			NumEnums:      2,
-			NumMessages:   12,
+			NumMessages:   14,
 			NumExtensions: 0,
 			NumServices:   1,
 		},
@@ -22,6 +22,7 @@ const (
 	GameStateService_JoinGame_FullMethodName        = "/gamestate.GameStateService/JoinGame"
 	GameStateService_GetGameState_FullMethodName    = "/gamestate.GameStateService/GetGameState"
 	GameStateService_UpdateGameState_FullMethodName = "/gamestate.GameStateService/UpdateGameState"
+	GameStateService_RestartGame_FullMethodName     = "/gamestate.GameStateService/RestartGame"
 )
 
 // GameStateServiceClient is the client API for GameStateService service.
@@ -31,6 +32,7 @@ type GameStateServiceClient interface {
 	JoinGame(ctx context.Context, in *JoinGameRequest, opts ...grpc.CallOption) (*JoinGameResponse, error)
 	GetGameState(ctx context.Context, in *GetGameStateRequest, opts ...grpc.CallOption) (*GameStateResponse, error)
 	UpdateGameState(ctx context.Context, in *UpdateGameStateRequest, opts ...grpc.CallOption) (*UpdateGameStateResponse, error)
+	RestartGame(ctx context.Context, in *RestartGameRequest, opts ...grpc.CallOption) (*RestartGameResponse, error)
 }
 
 type gameStateServiceClient struct {
@@ -71,6 +73,16 @@ func (c *gameStateServiceClient) UpdateGameState(ctx context.Context, in *Update
 	return out, nil
 }
 
+func (c *gameStateServiceClient) RestartGame(ctx context.Context, in *RestartGameRequest, opts ...grpc.CallOption) (*RestartGameResponse, error) {
+	cOpts := append([]grpc.CallOption{grpc.StaticMethod()}, opts...)
+	out := new(RestartGameResponse)
+	err := c.cc.Invoke(ctx, GameStateService_RestartGame_FullMethodName, in, out, cOpts...)
+	if err != nil {
+		return nil, err
+	}
+	return out, nil
+}
+
 // GameStateServiceServer is the server API for GameStateService service.
 // All implementations must embed UnimplementedGameStateServiceServer
 // for forward compatibility.
@@ -78,6 +90,7 @@ type GameStateServiceServer interface {
 	JoinGame(context.Context, *JoinGameRequest) (*JoinGameResponse, error)
 	GetGameState(context.Context, *GetGameStateRequest) (*GameStateResponse, error)
 	UpdateGameState(context.Context, *UpdateGameStateRequest) (*UpdateGameStateResponse, error)
+	RestartGame(context.Context, *RestartGameRequest) (*RestartGameResponse, error)
 	mustEmbedUnimplementedGameStateServiceServer()
 }
 
@@ -97,6 +110,9 @@ func (UnimplementedGameStateServiceServer) GetGameState(context.Context, *GetGam
 func (UnimplementedGameStateServiceServer) UpdateGameState(context.Context, *UpdateGameStateRequest) (*UpdateGameStateResponse, error) {
 	return nil, status.Errorf(codes.Unimplemented, "method UpdateGameState not implemented")
 }
+func (UnimplementedGameStateServiceServer) RestartGame(context.Context, *RestartGameRequest) (*RestartGameResponse, error) {
+	return nil, status.Errorf(codes.Unimplemented, "method RestartGame not implemented")
+}
 func (UnimplementedGameStateServiceServer) mustEmbedUnimplementedGameStateServiceServer() {}
 func (UnimplementedGameStateServiceServer) testEmbeddedByValue()                          {}
 
@@ -172,6 +188,24 @@ func _GameStateService_UpdateGameState_Handler(srv interface{}, ctx context.Cont
 	return interceptor(ctx, in, info, handler)
 }
 
+func _GameStateService_RestartGame_Handler(srv interface{}, ctx context.Context, dec func(interface{}) error, interceptor grpc.UnaryServerInterceptor) (interface{}, error) {
+	in := new(RestartGameRequest)
+	if err := dec(in); err != nil {
+		return nil, err
+	}
+	if interceptor == nil {
+		return srv.(GameStateServiceServer).RestartGame(ctx, in)
+	}
+	info := &grpc.UnaryServerInfo{
+		Server:     srv,
+		FullMethod: GameStateService_RestartGame_FullMethodName,
+	}
+	handler := func(ctx context.Context, req interface{}) (interface{}, error) {
+		return srv.(GameStateServiceServer).RestartGame(ctx, req.(*RestartGameRequest))
+	}
+	return interceptor(ctx, in, info, handler)
+}
+
 // GameStateService_ServiceDesc is the grpc.ServiceDesc for GameStateService service.
 // It's only intended for direct use with grpc.RegisterService,
 // and not to be introspected or modified (even as a copy)
@@ -191,6 +225,10 @@ var GameStateService_ServiceDesc = grpc.ServiceDesc{
 			MethodName: "UpdateGameState",
 			Handler:    _GameStateService_UpdateGameState_Handler,
 		},
+		{
+			MethodName: "RestartGame",
+			Handler:    _GameStateService_RestartGame_Handler,
+		},
 	},
 	Streams:  []grpc.StreamDesc{},
 	Metadata: "game_state.proto",
@@ -333,6 +333,10 @@ type GameState struct {
 	Height        int32                  `protobuf:"varint,3,opt,name=height,proto3" json:"height,omitempty"`
 	Grid          *Grid                  `protobuf:"bytes,4,opt,name=grid,proto3" json:"grid,omitempty"`
 	Players       map[int32]*Player      `protobuf:"bytes,5,rep,name=players,proto3" json:"players,omitempty" protobuf_key:"varint,1,opt,name=key" protobuf_val:"bytes,2,opt,name=value"`
+	MinPlayers    int32                  `protobuf:"varint,6,opt,name=min_players,json=minPlayers,proto3" json:"min_players,omitempty"` // jogadores necessários para iniciar
+	MaxPlayers    int32                  `protobuf:"varint,7,opt,name=max_players,json=maxPlayers,proto3" json:"max_players,omitempty"` // capacidade da sala
+	Version       *uint64                `protobuf:"varint,8,opt,name=version,proto3,oneof" json:"version,omitempty"`                   // versão em que o estado foi lido; o UpdateGameState recusa versões antigas
+	Epoch         *uint32                `protobuf:"varint,9,opt,name=epoch,proto3,oneof" json:"epoch,omitempty"`
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -402,6 +406,34 @@ func (x *GameState) GetPlayers() map[int32]*Player {
 	return nil
 }
 
+func (x *GameState) GetMinPlayers() int32 {
+	if x != nil {
+		return x.MinPlayers
+	}
+	return 0
+}
+
+func (x *GameState) GetMaxPlayers() int32 {
+	if x != nil {
+		return x.MaxPlayers
+	}
+	return 0
+}
+
+func (x *GameState) GetVersion() uint64 {
+	if x != nil && x.Version != nil {
+		return *x.Version
+	}
+	return 0
+}
+
+func (x *GameState) GetEpoch() uint32 {
+	if x != nil && x.Epoch != nil {
+		return *x.Epoch
+	}
+	return 0
+}
+
 // Request/Response messages
 type JoinGameRequest struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
@@ -443,6 +475,7 @@ type JoinGameResponse struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	Player        *Player                `protobuf:"bytes,1,opt,name=player,proto3" json:"player,omitempty"`
 	Error         string                 `protobuf:"bytes,2,opt,name=error,proto3" json:"error,omitempty"`
+	Token         string                 `protobuf:"bytes,3,opt,name=token,proto3" json:"token,omitempty"` // token de sessão exigido pelo UpdateGameState e pelo RestartGame; vazio se o servidor não autentica
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -491,6 +524,13 @@ func (x *JoinGameResponse) GetError() string {
 	return ""
 }
 
+func (x *JoinGameResponse) GetToken() string {
+	if x != nil {
+		return x.Token
+	}
+	return ""
+}
+
 type GetGameStateRequest struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	unknownFields protoimpl.UnknownFields
@@ -661,6 +701,7 @@ func (x *UpdateGameStateResponse) GetSuccess() bool {
 
 type RestartGameRequest struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
+	KeepPlayers   bool                   `protobuf:"varint,1,opt,name=keep_players,json=keepPlayers,proto3" json:"keep_players,omitempty"` // revanche com os mesmos jogadores
 	unknownFields protoimpl.UnknownFields
 	sizeCache     protoimpl.SizeCache
 }
@@ -695,6 +736,13 @@ func (*RestartGameRequest) Descriptor() ([]byte, []int) {
 	return file_service_b_game_state_proto_rawDescGZIP(), []int{11}
 }
 
+func (x *RestartGameRequest) GetKeepPlayers() bool {
+	if x != nil {
+		return x.KeepPlayers
+	}
+	return false
+}
+
 type RestartGameResponse struct {
 	state         protoimpl.MessageState `protogen:"open.v1"`
 	unknownFields protoimpl.UnknownFields
@@ -747,28 +795,39 @@ const file_service_b_game_state_proto_rawDesc = "" +
 	"\x04Grid\x12&\n" +
 	"\x04rows\x18\x01 \x03(\v2\x12.gamestate.GridRowR\x04rows\"0\n" +
 	"\aGridRow\x12%\n" +
-	"\x05cells\x18\x01 \x03(\v2\x0f.gamestate.CellR\x05cells\"\x99\x02\n" +
+	"\x05cells\x18\x01 \x03(\v2\x0f.gamestate.CellR\x05cells\"\xab\x03\n" +
 	"\tGameState\x12-\n" +
 	"\x06status\x18\x01 \x01(\x0e2\x15.gamestate.GameStatusR\x06status\x12\x14\n" +
 	"\x05width\x18\x02 \x01(\x05R\x05width\x12\x16\n" +
 	"\x06height\x18\x03 \x01(\x05R\x06height\x12#\n" +
 	"\x04grid\x18\x04 \x01(\v2\x0f.gamestate.GridR\x04grid\x12;\n" +
-	"\aplayers\x18\x05 \x03(\v2!.gamestate.GameState.PlayersEntryR\aplayers\x1aM\n" +
+	"\aplayers\x18\x05 \x03(\v2!.gamestate.GameState.PlayersEntryR\aplayers\x12\x1f\n" +
+	"\vmin_players\x18\x06 \x01(\x05R\n" +
+	"minPlayers\x12\x1f\n" +
+	"\vmax_players\x18\a \x01(\x05R\n" +
+	"maxPlayers\x12\x1d\n" +
+	"\aversion\x18\b \x01(\x04H\x00R\aversion\x88\x01\x01\x12\x19\n" +
+	"\x05epoch\x18\t \x01(\rH\x01R\x05epoch\x88\x01\x01\x1aM\n" +
 	"\fPlayersEntry\x12\x10\n" +
 	"\x03key\x18\x01 \x01(\x05R\x03key\x12'\n" +
-	"\x05value\x18\x02 \x01(\v2\x11.gamestate.PlayerR\x05value:\x028\x01\"\x11\n" +
-	"\x0fJoinGameRequest\"S\n" +
+	"\x05value\x18\x02 \x01(\v2\x11.gamestate.PlayerR\x05value:\x028\x01B\n" +
+	"\n" +
+	"\b_versionB\b\n" +
+	"\x06_epoch\"\x11\n" +
+	"\x0fJoinGameRequest\"i\n" +
 	"\x10JoinGameResponse\x12)\n" +
 	"\x06player\x18\x01 \x01(\v2\x11.gamestate.PlayerR\x06player\x12\x14\n" +
-	"\x05error\x18\x02 \x01(\tR\x05error\"\x15\n" +
+	"\x05error\x18\x02 \x01(\tR\x05error\x12\x14\n" +
+	"\x05token\x18\x03 \x01(\tR\x05token\"\x15\n" +
 	"\x13GetGameStateRequest\"?\n" +
 	"\x11GameStateResponse\x12*\n" +
 	"\x05state\x18\x01 \x01(\v2\x14.gamestate.GameStateR\x05state\"D\n" +
 	"\x16UpdateGameStateRequest\x12*\n" +
 	"\x05state\x18\x01 \x01(\v2\x14.gamestate.GameStateR\x05state\"3\n" +
 	"\x17UpdateGameStateResponse\x12\x18\n" +
-	"\asuccess\x18\x01 \x01(\bR\asuccess\"\x14\n" +
-	"\x12RestartGameRequest\"\x15\n" +
+	"\asuccess\x18\x01 \x01(\bR\asuccess\"7\n" +
+	"\x12RestartGameRequest\x12!\n" +
+	"\fkeep_players\x18\x01 \x01(\bR\vkeepPlayers\"\x15\n" +
 	"\x13RestartGameResponse*D\n" +
 	"\n" +
 	"GameStatus\x12\x17\n" +
@@ -847,6 +906,7 @@ func file_service_b_game_state_proto_init() {
 	if File_service_b_game_state_proto != nil {
 		return
 	}
+	file_service_b_game_state_proto_msgTypes[4].OneofWrappers = []any{}
 	type x struct{}
 	out := protoimpl.TypeBuilder{
 		File: protoimpl.DescBuilder{
@@ -13,6 +13,7 @@ import (
 	"google.golang.org/grpc/codes"
 	"google.golang.org/grpc/reflection"
 	"google.golang.org/grpc/status"
+	"google.golang.org/protobuf/proto"
 )
 
 type GameStateServer struct {
@@ -32,7 +33,7 @@ func (s *GameStateServer) GetGameState(ctx context.Context, req *pb.GetGameState
 	defer s.mu.Unlock()
 
 	return &pb.GameStateResponse{
-		State: s.state,
+		State: proto.Clone(s.state).(*pb.GameState),
 	}, nil
 }
 
@@ -46,23 +47,15 @@ func (s *GameStateServer) JoinGame(ctx context.Context, req *pb.JoinGameRequest)
 		}, status.Error(codes.FailedPrecondition, "jogo não está no estado de espera")
 	}
 
-	positions := [][2]int32{
-		{s.state.Width / 2, 0},
-		{s.state.Width / 2, s.state.Height - 1},
-		{0, s.state.Height / 2},
-		{s.state.Width - 1, s.state.Height / 2},
-	}
-	colors := []string{"#FF5733", "#33C4FF", "#A2FF33", "#F733FF"}
-
 	nextPlayerID := int32(len(s.state.Players) + 1)
 
-	if nextPlayerID > 4 {
+	if nextPlayerID > s.state.MaxPlayers {
 		return &pb.JoinGameResponse{
 			Error: "Número máximo de jogadores atingido",
 		}, status.Error(codes.ResourceExhausted, "número máximo de jogadores atingido")
 	}
 
-	pos := positions[nextPlayerID-1]
+	pos := spawnPosition(s.state, nextPlayerID)
 	color := colors[nextPlayerID-1]
 
 	newPlayer := &pb.Player{
@@ -84,9 +77,10 @@ func (s *GameStateServer) JoinGame(ctx context.Context, req *pb.JoinGameRequest)
 		OwnerId: nextPlayerID,
 	}
 
-	if len(s.state.Players) == 2 {
+	if int32(len(s.state.Players)) == s.state.MinPlayers {
 		s.state.Status = pb.GameStatus_IN_PROGRESS
 	}
+	s.bumpVersion()
 
 	return &pb.JoinGameResponse{
 		Player: newPlayer,
@@ -97,7 +91,19 @@ func (s *GameStateServer) UpdateGameState(ctx context.Context, req *pb.UpdateGam
 	s.mu.Lock()
 	defer s.mu.Unlock()
 
-	s.state = req.State
+	incoming := req.GetState()
+	if incoming == nil || incoming.Version == nil {
+		return nil, status.Error(codes.InvalidArgument, "o estado precisa informar a versão em que foi lido")
+	}
+	if incoming.GetVersion() != s.state.GetVersion() || incoming.GetEpoch() != s.state.GetEpoch() {
+		return nil, status.Errorf(codes.Aborted, "estado desatualizado: lido na versão %d, o jogo está na versão %d",
+			incoming.GetVersion(), s.state.GetVersion())
+	}
+
+	incoming.MinPlayers = s.state.MinPlayers
+	incoming.MaxPlayers = s.state.MaxPlayers
+	s.state = incoming
+	s.bumpVersion()
 
 	return &pb.UpdateGameStateResponse{
 		Success: true,
@@ -108,12 +114,48 @@ func (s *GameStateServer) RestartGame(ctx context.Context, req *pb.RestartGameRe
 	s.mu.Lock()
 	defer s.mu.Unlock()
 
-	s.state = createInitialState(s.state.Width, s.state.Height)
-	log.Println("O jogo foi reiniciado")
+	previous := s.state
+	s.state = createInitialState(previous.Width, previous.Height)
+	s.state.Version = proto.Uint64(previous.GetVersion() + 1)
+
+	// Na revanche os jogadores voltam às posições iniciais na mesma época; sem ela a sala é esvaziada
+	if req.GetKeepPlayers() {
+		s.state.Epoch = proto.Uint32(previous.GetEpoch())
+		for id, player := range previous.Players {
+			pos := spawnPosition(s.state, id)
+			player.X, player.Y = pos[0], pos[1]
+			s.state.Players[id] = player
+			s.state.Grid.Rows[pos[1]].Cells[pos[0]] = &pb.Cell{
+				State:   pb.CellState_OWNED,
+				OwnerId: id,
+			}
+		}
+		if int32(len(s.state.Players)) >= s.state.MinPlayers {
+			s.state.Status = pb.GameStatus_IN_PROGRESS
+		}
+	} else {
+		s.state.Epoch = proto.Uint32(previous.GetEpoch() + 1)
+	}
+	log.Printf("O jogo foi reiniciado (mantendo os jogadores: %v)", req.GetKeepPlayers())
 
 	return &pb.RestartGameResponse{}, nil
 }
 
+func (s *GameStateServer) bumpVersion() {
+	s.state.Version = proto.Uint64(s.state.GetVersion() + 1)
+}
+
+var colors = []string{"#FF5733", "#33C4FF", "#A2FF33", "#F733FF"}
+
+func spawnPosition(state *pb.GameState, playerID int32) [2]int32 {
+	positions := [][2]int32{
+		{state.Width / 2, 0},
+		{state.Width / 2, state.Height - 1},
+		{0, state.Height / 2},
+		{state.Width - 1, state.Height / 2},
+	}
+	return positions[playerID-1]
+}
 
 func createInitialState(width, height int32) *pb.GameState {
 	grid := &pb.Grid{
@@ -134,11 +176,15 @@ func createInitialState(width, height int32) *pb.GameState {
 	}
 
 	return &pb.GameState{
-		Status:  pb.GameStatus_WAITING_FOR_PLAYERS,
-		Width:   width,
-		Height:  height,
-		Grid:    grid,
-		Players: make(map[int32]*pb.Player),
+		Status:     pb.GameStatus_WAITING_FOR_PLAYERS,
+		Width:      width,
+		Height:     height,
+		Grid:       grid,
+		Players:    make(map[int32]*pb.Player),
+		MinPlayers: 2,
+		MaxPlayers: 4,
+		Version:    proto.Uint64(0),
+		Epoch:      proto.Uint32(0),
 	}
 }
 
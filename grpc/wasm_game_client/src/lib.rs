@@ -1,3 +0,0 @@
-mod app;
-mod drawing;
-mod utils;
@@ -10,5 +10,6 @@ COPY ./dist/linux/servico_b /app/servico_b
 RUN chmod +x /app/servico_b
 
 EXPOSE 3001
+EXPOSE 50051
 
 CMD ["./servico_b"]
\ No newline at end of file
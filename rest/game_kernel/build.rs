@@ -0,0 +1,39 @@
+// ===================================================================================
+// TIPOS GERADOS A PARTIR DOS .proto DOS SERVIÇOS gRPC (feature "grpc")
+// ===================================================================================
+
+#[cfg(feature = "grpc")]
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    use prost::Message;
+
+    const PROTOS: [&str; 2] = ["../../grpc/service_b/game_state.proto", "../../grpc/service_a/game_movement.proto"];
+    const INCLUDES: [&str; 2] = ["../../grpc/service_b", "../../grpc/service_a"];
+
+    for proto in PROTOS {
+        println!("cargo:rerun-if-changed={}", proto);
+    }
+
+    let descriptors = protox::compile(PROTOS, INCLUDES)?;
+    let encoded = descriptors.encode_to_vec();
+    let mut config = prost_build::Config::new();
+    #[cfg(any(feature = "grpc-client", feature = "grpc-server"))]
+    config.service_generator(
+        tonic_prost_build::configure()
+            .build_client(cfg!(feature = "grpc-client"))
+            .build_server(cfg!(feature = "grpc-server"))
+            .service_generator(),
+    );
+    config.compile_fds(descriptors)?;
+
+    // O gateway Ruby usa encode_json(emit_defaults: true): campos em camelCase, enums pelo nome
+    // e chaves de mapa como strings, sempre presentes mesmo com o valor padrão.
+    pbjson_build::Builder::new()
+        .register_descriptors(&encoded)?
+        .emit_fields()
+        .build(&[".gamestate", ".gamemovement"])?;
+
+    Ok(())
+}
+
+#[cfg(not(feature = "grpc"))]
+fn main() {}
@@ -0,0 +1,305 @@
+use crate::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+pub const DELTA_HISTORY_CAPACITY: usize = 256;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct CellChange {
+    pub x: usize,
+    pub y: usize,
+    pub cell: CellState,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct StateDelta {
+    pub from_version: u64,
+    pub to_version: u64,
+    pub status: GameStatus,
+    pub cells: Vec<CellChange>,
+    pub players: Vec<Player>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct UpdateAccepted {
+    pub version: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct VersionConflict {
+    pub expected_version: u64,
+    pub current_version: u64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(tag = "tipo", content = "dados", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StateUpdate {
+    Snapshot(GameState),
+    Delta(StateDelta),
+}
+
+// ===================================================================================
+// CÁLCULO E APLICAÇÃO DE DIFERENÇAS
+// ===================================================================================
+
+impl GameState {
+    pub fn diff(&self, newer: &GameState) -> Option<StateDelta> {
+        let players_removed = self.players.keys().any(|id| !newer.players.contains_key(id));
+        if self.width != newer.width || self.height != newer.height || players_removed {
+            return None;
+        }
+
+        let cells = newer
+            .grid
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, &cell)| CellChange { x, y, cell }))
+            .filter(|change| self.grid[change.y][change.x] != change.cell)
+            .collect();
+
+        let players = newer
+            .players
+            .values()
+            .filter(|p| self.players.get(&p.id).is_none_or(|old| (old.x, old.y, &old.color) != (p.x, p.y, &p.color)))
+            .cloned()
+            .collect();
+
+        Some(StateDelta {
+            from_version: self.version,
+            to_version: newer.version,
+            status: newer.status,
+            cells,
+            players,
+        })
+    }
+
+    pub fn apply_delta(&mut self, delta: &StateDelta) {
+        for change in &delta.cells {
+            if let Some(cell) = self.grid.get_mut(change.y).and_then(|row| row.get_mut(change.x)) {
+                *cell = change.cell;
+            }
+        }
+        for player in &delta.players {
+            self.players.insert(player.id, player.clone());
+        }
+        self.status = delta.status;
+        self.version = delta.to_version;
+    }
+}
+
+impl StateDelta {
+    fn merge(&mut self, next: &StateDelta) {
+        for change in &next.cells {
+            self.cells.retain(|c| (c.x, c.y) != (change.x, change.y));
+            self.cells.push(*change);
+        }
+        for player in &next.players {
+            self.players.retain(|p| p.id != player.id);
+            self.players.push(player.clone());
+        }
+        self.status = next.status;
+        self.to_version = next.to_version;
+    }
+}
+
+impl StateUpdate {
+    pub fn version(&self) -> u64 {
+        match self {
+            StateUpdate::Snapshot(state) => state.version,
+            StateUpdate::Delta(delta) => delta.to_version,
+        }
+    }
+
+    pub fn apply_to(self, state: &mut Option<GameState>) -> bool {
+        match (self, state.as_mut()) {
+            (StateUpdate::Snapshot(snapshot), _) => {
+                *state = Some(snapshot);
+                true
+            }
+            (StateUpdate::Delta(delta), Some(current)) if current.version == delta.from_version => {
+                current.apply_delta(&delta);
+                true
+            }
+            (StateUpdate::Delta(_), _) => false,
+        }
+    }
+}
+
+// ===================================================================================
+// HISTÓRICO DE VERSÕES
+// ===================================================================================
+
+#[derive(Clone, Debug)]
+pub struct DeltaHistory {
+    capacity: usize,
+    deltas: VecDeque<StateDelta>,
+}
+
+impl Default for DeltaHistory {
+    fn default() -> Self {
+        DeltaHistory::new(DELTA_HISTORY_CAPACITY)
+    }
+}
+
+impl DeltaHistory {
+    pub fn new(capacity: usize) -> Self {
+        DeltaHistory { capacity, deltas: VecDeque::with_capacity(capacity) }
+    }
+
+    pub fn clear(&mut self) {
+        self.deltas.clear();
+    }
+
+    pub fn record_transition(&mut self, previous: &GameState, current: &mut GameState) {
+        current.version = previous.version + 1;
+        match previous.diff(current) {
+            Some(delta) => {
+                if self.deltas.len() == self.capacity {
+                    self.deltas.pop_front();
+                }
+                self.deltas.push_back(delta);
+            }
+            None => self.clear(),
+        }
+    }
+
+    pub fn updates_since(&self, version: u64, current: &GameState) -> StateUpdate {
+        if version == current.version {
+            return StateUpdate::Delta(StateDelta {
+                from_version: version,
+                to_version: version,
+                status: current.status,
+                cells: Vec::new(),
+                players: Vec::new(),
+            });
+        }
+
+        let Some(start) = self.deltas.iter().position(|d| d.from_version == version) else {
+            return StateUpdate::Snapshot(current.clone());
+        };
+
+        let mut merged = self.deltas[start].clone();
+        for delta in self.deltas.iter().skip(start + 1) {
+            merged.merge(delta);
+        }
+        StateUpdate::Delta(merged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn running_game() -> GameState {
+        let mut game = create_initial_state(4, 4, 2, 2);
+        game.join_player().unwrap();
+        game.join_player().unwrap();
+        game
+    }
+
+    fn assert_same_state(left: &GameState, right: &GameState) {
+        assert_eq!(left.version, right.version);
+        assert_eq!(left.status, right.status);
+        assert_eq!(left.grid, right.grid);
+        let positions = |game: &GameState| {
+            let mut players: Vec<_> = game.players.values().map(|p| (p.id, p.x, p.y, p.color.clone())).collect();
+            players.sort();
+            players
+        };
+        assert_eq!(positions(left), positions(right));
+    }
+
+    #[test]
+    fn applying_a_diff_reproduces_the_newer_state() {
+        let base = running_game();
+        let mut newer = base.clone();
+        newer.apply_move(1, Direction::Left).unwrap();
+        newer.apply_move(2, Direction::Right).unwrap();
+        newer.version = base.version + 1;
+
+        let delta = base.diff(&newer).unwrap();
+        assert_eq!((delta.from_version, delta.to_version), (0, 1));
+        assert_eq!(delta.cells.len(), 2);
+        assert_eq!(delta.players.len(), 2);
+
+        let mut patched = base.clone();
+        patched.apply_delta(&delta);
+        assert_same_state(&patched, &newer);
+    }
+
+    #[test]
+    fn diff_needs_the_same_map_and_players() {
+        let base = running_game();
+        assert!(base.diff(&create_initial_state(5, 4, 2, 2)).is_none());
+
+        let mut without_players = base.clone();
+        without_players.players.clear();
+        assert!(base.diff(&without_players).is_none());
+    }
+
+    #[test]
+    fn deltas_only_apply_on_top_of_their_base_version() {
+        let base = running_game();
+        let mut newer = base.clone();
+        newer.apply_move(1, Direction::Left).unwrap();
+        newer.version = 1;
+        let delta = base.diff(&newer).unwrap();
+
+        let mut stale = Some(base.clone());
+        stale.as_mut().unwrap().version = 7;
+        assert!(!StateUpdate::Delta(delta.clone()).apply_to(&mut stale));
+        assert_eq!(stale.as_ref().unwrap().version, 7);
+
+        let mut empty = None;
+        assert!(!StateUpdate::Delta(delta.clone()).apply_to(&mut empty));
+        assert!(StateUpdate::Snapshot(newer.clone()).apply_to(&mut empty));
+        assert_same_state(empty.as_ref().unwrap(), &newer);
+
+        let mut current = Some(base);
+        assert!(StateUpdate::Delta(delta).apply_to(&mut current));
+        assert_same_state(current.as_ref().unwrap(), &newer);
+    }
+
+    #[test]
+    fn history_merges_deltas_since_a_known_version() {
+        let mut history = DeltaHistory::default();
+        let first = running_game();
+        let mut live = first.clone();
+        for (player_id, direction) in [(1, Direction::Left), (2, Direction::Right), (1, Direction::Down)] {
+            let previous = live.clone();
+            live.apply_move(player_id, direction).unwrap();
+            history.record_transition(&previous, &mut live);
+        }
+        assert_eq!(live.version, 3);
+
+        let mut client = Some(first);
+        let update = history.updates_since(0, &live);
+        assert!(matches!(update, StateUpdate::Delta(_)));
+        assert!(update.apply_to(&mut client));
+        assert_same_state(client.as_ref().unwrap(), &live);
+
+        let StateUpdate::Delta(empty) = history.updates_since(3, &live) else {
+            panic!("a versão atual deveria gerar um delta vazio");
+        };
+        assert!(empty.cells.is_empty() && empty.players.is_empty());
+        assert!(matches!(history.updates_since(42, &live), StateUpdate::Snapshot(_)));
+    }
+
+    #[test]
+    fn history_falls_back_to_snapshots_after_eviction_or_reset() {
+        let mut history = DeltaHistory::new(1);
+        let mut live = running_game();
+        for direction in [Direction::Left, Direction::Down] {
+            let previous = live.clone();
+            live.apply_move(1, direction).unwrap();
+            history.record_transition(&previous, &mut live);
+        }
+        assert!(matches!(history.updates_since(0, &live), StateUpdate::Snapshot(_)));
+        assert!(matches!(history.updates_since(1, &live), StateUpdate::Delta(_)));
+
+        let previous = live.clone();
+        live.players.clear();
+        history.record_transition(&previous, &mut live);
+        assert!(matches!(history.updates_since(1, &live), StateUpdate::Snapshot(_)));
+    }
+}
@@ -0,0 +1,225 @@
+use crate::*;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(tag = "codigo", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MoveError {
+    GameNotInProgress { status: GameStatus },
+    UnknownPlayer { player_id: PlayerId },
+    InvalidDirection,
+    OutOfBounds { direction: Direction },
+    CellOwnedByOtherPlayer { owner: PlayerId },
+}
+
+impl fmt::Display for MoveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MoveError::GameNotInProgress { status: GameStatus::WaitingForPlayers } => {
+                f.write_str("O jogo ainda não começou")
+            }
+            MoveError::GameNotInProgress { .. } => f.write_str("O jogo já terminou"),
+            MoveError::UnknownPlayer { player_id } => write!(f, "Jogador {} não encontrado", player_id),
+            MoveError::InvalidDirection => f.write_str("Direção inválida (use UP, DOWN, LEFT ou RIGHT)"),
+            MoveError::OutOfBounds { direction } => write!(f, "Movimento inválido: fora dos limites ({})", direction),
+            MoveError::CellOwnedByOtherPlayer { owner } => write!(f, "A célula pertence ao Jogador {}", owner),
+        }
+    }
+}
+
+impl MoveError {
+    pub fn code(&self) -> &'static str {
+        match self {
+            MoveError::GameNotInProgress { .. } => "GAME_NOT_IN_PROGRESS",
+            MoveError::UnknownPlayer { .. } => "UNKNOWN_PLAYER",
+            MoveError::InvalidDirection => "INVALID_DIRECTION",
+            MoveError::OutOfBounds { .. } => "OUT_OF_BOUNDS",
+            MoveError::CellOwnedByOtherPlayer { .. } => "CELL_OWNED_BY_OTHER_PLAYER",
+        }
+    }
+}
+
+impl std::error::Error for MoveError {}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ErrorBody {
+    pub erro: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct MoveErrorBody {
+    pub erro: String,
+    pub motivo: MoveError,
+}
+
+impl From<MoveError> for MoveErrorBody {
+    fn from(motivo: MoveError) -> Self {
+        MoveErrorBody { erro: motivo.to_string(), motivo }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum JoinError {
+    GameAlreadyStarted,
+    GameFull,
+}
+
+impl fmt::Display for JoinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            JoinError::GameAlreadyStarted => "O jogo já começou",
+            JoinError::GameFull => "O jogo está cheio",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for JoinError {}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RestartError {
+    GameNotFinished,
+}
+
+impl fmt::Display for RestartError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RestartError::GameNotFinished => f.write_str("Só é possível reiniciar uma partida encerrada"),
+        }
+    }
+}
+
+impl std::error::Error for RestartError {}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum RoomError {
+    InvalidDimensions { width: usize, height: usize },
+    InvalidPlayerCount { max_players: usize, limits: RoomLimits },
+    InvalidPlayerLimits { min_players: usize, max_players: usize },
+    InvalidStartThreshold { min_players: usize, max_players: usize },
+    InvalidColor { color: String },
+}
+
+impl fmt::Display for RoomError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RoomError::InvalidDimensions { width, height } => write!(
+                f,
+                "Dimensões inválidas ({}x{}): use entre {} e {} células por lado",
+                width, height, MIN_BOARD_SIZE, MAX_BOARD_SIZE
+            ),
+            RoomError::InvalidPlayerCount { max_players, limits } => write!(
+                f,
+                "Número de jogadores inválido ({}): use entre {} e {}",
+                max_players, limits.min_players, limits.max_players
+            ),
+            RoomError::InvalidPlayerLimits { min_players, max_players } => write!(
+                f,
+                "Limites de jogadores inválidos ({} a {}): o mínimo deve ser ao menos 1 e o máximo no máximo {}",
+                min_players, max_players, MAX_PLAYERS
+            ),
+            RoomError::InvalidStartThreshold { min_players, max_players } => write!(
+                f,
+                "Mínimo de jogadores para iniciar inválido ({}): use entre 1 e {}",
+                min_players, max_players
+            ),
+            RoomError::InvalidColor { color } => write!(f, "Cor inválida '{}': use o formato #RRGGBB", color),
+        }
+    }
+}
+
+impl std::error::Error for RoomError {}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ReplayError {
+    UnsupportedVersion { format_version: u32 },
+    InvalidMove { event: usize, reason: MoveError },
+    Diverged { event: usize },
+}
+
+impl fmt::Display for ReplayError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReplayError::UnsupportedVersion { format_version } => {
+                write!(f, "Versão de replay não suportada: {} (esperada {})", format_version, REPLAY_FORMAT_VERSION)
+            }
+            ReplayError::InvalidMove { event, reason } => write!(f, "Evento {} inválido: {}", event, reason),
+            ReplayError::Diverged { event } => write!(f, "O replay divergiu do registro no evento {}", event),
+        }
+    }
+}
+
+impl std::error::Error for ReplayError {}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum SessionError {
+    MissingToken,
+    MalformedToken,
+    InvalidSignature,
+    WrongRoom,
+    WrongPlayer,
+    StaleSession,
+}
+
+impl fmt::Display for SessionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let message = match self {
+            SessionError::MissingToken => "Token de sessão ausente",
+            SessionError::MalformedToken => "Token de sessão malformado",
+            SessionError::InvalidSignature => "Token de sessão inválido",
+            SessionError::WrongRoom => "O token de sessão pertence a outra sala",
+            SessionError::WrongPlayer => "O token de sessão pertence a outro jogador",
+            SessionError::StaleSession => "O token de sessão é de uma partida anterior",
+        };
+        f.write_str(message)
+    }
+}
+
+impl std::error::Error for SessionError {}
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum WireError {
+    InvalidPlayerId { id: i32 },
+    InvalidDimensions { width: i32, height: i32 },
+    InvalidPosition { player_id: PlayerId, x: i32, y: i32 },
+    GridMismatch { width: usize, height: usize },
+    PlayerKeyMismatch { key: i32, player_id: PlayerId },
+    UnknownEnumValue { field: &'static str, value: i32 },
+    MissingField { field: &'static str },
+    InvalidPlayerLimits { min_players: i32, max_players: i32 },
+    UnsupportedValue { field: &'static str, value: i32 },
+    UnknownOwner { owner: PlayerId },
+}
+
+impl fmt::Display for WireError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WireError::InvalidPlayerId { id } => write!(f, "Identificador de jogador inválido: {}", id),
+            WireError::InvalidDimensions { width, height } => {
+                write!(f, "Dimensões do mapa inválidas: {}x{}", width, height)
+            }
+            WireError::InvalidPosition { player_id, x, y } => {
+                write!(f, "Jogador {} fora do mapa em ({}, {})", player_id, x, y)
+            }
+            WireError::GridMismatch { width, height } => {
+                write!(f, "A grade não corresponde às dimensões {}x{}", width, height)
+            }
+            WireError::PlayerKeyMismatch { key, player_id } => {
+                write!(f, "Jogador {} registrado sob a chave {}", player_id, key)
+            }
+            WireError::UnknownEnumValue { field, value } => write!(f, "Valor desconhecido para {}: {}", field, value),
+            WireError::MissingField { field } => write!(f, "Campo obrigatório ausente: {}", field),
+            WireError::InvalidPlayerLimits { min_players, max_players } => write!(
+                f,
+                "Limites de jogadores inválidos: mínimo {} e máximo {} (use entre 1 e {})",
+                min_players, max_players, MAX_PLAYERS
+            ),
+            WireError::UnknownOwner { owner } => write!(f, "Célula pertence ao jogador {}, que não está na partida", owner),
+            WireError::UnsupportedValue { field, value } => {
+                write!(f, "O kernel não representa {} = {}", field, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for WireError {}
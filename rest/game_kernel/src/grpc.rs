@@ -0,0 +1,381 @@
+use crate::{CellState as DomainCell, Direction, PlayerId, WireError, MAX_PLAYERS, MIN_PLAYERS_TO_START};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// ===================================================================================
+// TIPOS GERADOS A PARTIR DOS .proto (ver build.rs)
+// ===================================================================================
+
+pub mod gamestate {
+    include!(concat!(env!("OUT_DIR"), "/gamestate.rs"));
+    include!(concat!(env!("OUT_DIR"), "/gamestate.serde.rs"));
+}
+
+pub mod gamemovement {
+    include!(concat!(env!("OUT_DIR"), "/gamemovement.rs"));
+    include!(concat!(env!("OUT_DIR"), "/gamemovement.serde.rs"));
+}
+
+// Corpo aceito pelo POST /game/move do gateway Ruby, que não é uma mensagem protobuf.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct MovePayload {
+    pub player_id: i32,
+    pub direction: Direction,
+}
+
+// ===================================================================================
+// CONVERSÕES ENTRE O MODELO DO KERNEL E AS MENSAGENS gRPC
+// ===================================================================================
+
+fn player_id(id: i32) -> Result<PlayerId, WireError> {
+    PlayerId::try_from(id).map_err(|_| WireError::InvalidPlayerId { id })
+}
+
+// Os dois pacotes repetem as mesmas mensagens; as conversões usam literais e desestruturações
+// completas para que um campo novo no .proto quebre a compilação em vez de ser descartado.
+// Campos sem equivalente no kernel (`current_turn`) saem com o valor padrão e qualquer outro
+// valor é recusado na volta.
+macro_rules! wire_conversions {
+    ($package:ident $(, $extra:ident: $default:expr)*) => {
+        impl From<crate::GameStatus> for $package::GameStatus {
+            fn from(status: crate::GameStatus) -> Self {
+                match status {
+                    crate::GameStatus::WaitingForPlayers => $package::GameStatus::WaitingForPlayers,
+                    crate::GameStatus::InProgress => $package::GameStatus::InProgress,
+                    crate::GameStatus::Finished => $package::GameStatus::Finished,
+                }
+            }
+        }
+
+        impl From<$package::GameStatus> for crate::GameStatus {
+            fn from(status: $package::GameStatus) -> Self {
+                match status {
+                    $package::GameStatus::WaitingForPlayers => crate::GameStatus::WaitingForPlayers,
+                    $package::GameStatus::InProgress => crate::GameStatus::InProgress,
+                    $package::GameStatus::Finished => crate::GameStatus::Finished,
+                }
+            }
+        }
+
+        impl From<DomainCell> for $package::Cell {
+            fn from(cell: DomainCell) -> Self {
+                match cell {
+                    DomainCell::Neutral => $package::Cell { state: $package::CellState::Neutral.into(), owner_id: 0 },
+                    DomainCell::Owned(owner) => {
+                        $package::Cell { state: $package::CellState::Owned.into(), owner_id: owner.into() }
+                    }
+                }
+            }
+        }
+
+        impl TryFrom<$package::Cell> for DomainCell {
+            type Error = WireError;
+
+            fn try_from(cell: $package::Cell) -> Result<Self, WireError> {
+                let $package::Cell { state, owner_id } = cell;
+                let state = $package::CellState::try_from(state)
+                    .map_err(|_| WireError::UnknownEnumValue { field: "Cell.state", value: state })?;
+                match state {
+                    $package::CellState::Neutral => Ok(DomainCell::Neutral),
+                    $package::CellState::Owned => Ok(DomainCell::Owned(player_id(owner_id)?)),
+                }
+            }
+        }
+
+        impl From<&crate::Player> for $package::Player {
+            fn from(player: &crate::Player) -> Self {
+                $package::Player {
+                    id: player.id.into(),
+                    x: player.x as i32,
+                    y: player.y as i32,
+                    color: player.color.clone(),
+                }
+            }
+        }
+
+        impl TryFrom<$package::Player> for crate::Player {
+            type Error = WireError;
+
+            fn try_from(player: $package::Player) -> Result<Self, WireError> {
+                let $package::Player { id, x, y, color } = player;
+                let id = player_id(id)?;
+                let invalid = || WireError::InvalidPosition { player_id: id, x, y };
+                Ok(crate::Player {
+                    id,
+                    x: usize::try_from(x).map_err(|_| invalid())?,
+                    y: usize::try_from(y).map_err(|_| invalid())?,
+                    color,
+                })
+            }
+        }
+
+        impl From<&crate::GameState> for $package::GameState {
+            fn from(game: &crate::GameState) -> Self {
+                $package::GameState {
+                    status: $package::GameStatus::from(game.status).into(),
+                    width: game.width as i32,
+                    height: game.height as i32,
+                    grid: Some($package::Grid {
+                        rows: game
+                            .grid
+                            .iter()
+                            .map(|row| $package::GridRow { cells: row.iter().map(|&cell| cell.into()).collect() })
+                            .collect(),
+                    }),
+                    players: game.players.values().map(|player| (player.id.into(), player.into())).collect(),
+                    min_players: game.min_players as i32,
+                    max_players: game.max_players as i32,
+                    version: Some(game.version),
+                    epoch: Some(game.epoch),
+                    $($extra: $default,)*
+                }
+            }
+        }
+
+        impl From<crate::GameState> for $package::GameState {
+            fn from(game: crate::GameState) -> Self {
+                $package::GameState::from(&game)
+            }
+        }
+
+        impl TryFrom<$package::GameState> for crate::GameState {
+            type Error = WireError;
+
+            fn try_from(wire: $package::GameState) -> Result<Self, WireError> {
+                let $package::GameState {
+                    status,
+                    width,
+                    height,
+                    grid,
+                    players,
+                    min_players,
+                    max_players,
+                    version,
+                    epoch,
+                    $($extra,)*
+                } = wire;
+                $(
+                    if $extra != $default {
+                        return Err(WireError::UnsupportedValue {
+                            field: concat!("GameState.", stringify!($extra)),
+                            value: $extra,
+                        });
+                    }
+                )*
+                let status = $package::GameStatus::try_from(status)
+                    .map_err(|_| WireError::UnknownEnumValue { field: "GameState.status", value: status })?;
+
+                let invalid_dimensions = || WireError::InvalidDimensions { width, height };
+                let columns = usize::try_from(width).ok().filter(|&w| w > 0).ok_or_else(invalid_dimensions)?;
+                let rows = usize::try_from(height).ok().filter(|&h| h > 0).ok_or_else(invalid_dimensions)?;
+
+                // Servidores gerados antes destes campos não os preenchem: zero e ausência valem
+                // os padrões de uma sala nova, como no JSON do REST.
+                let invalid_limits = || WireError::InvalidPlayerLimits { min_players, max_players };
+                let capacity = match max_players {
+                    0 => MAX_PLAYERS,
+                    max => usize::try_from(max)
+                        .ok()
+                        .filter(|&max| (1..=MAX_PLAYERS).contains(&max))
+                        .ok_or_else(invalid_limits)?,
+                };
+                let threshold = match min_players {
+                    0 => MIN_PLAYERS_TO_START.min(capacity),
+                    min => usize::try_from(min)
+                        .ok()
+                        .filter(|&min| (1..=capacity).contains(&min))
+                        .ok_or_else(invalid_limits)?,
+                };
+                let version = version.unwrap_or_default();
+                let epoch = epoch.unwrap_or_default();
+
+                let grid = grid.map(|grid| grid.rows).unwrap_or_default();
+                if grid.len() != rows || grid.iter().any(|row| row.cells.len() != columns) {
+                    return Err(WireError::GridMismatch { width: columns, height: rows });
+                }
+                let grid: Vec<Vec<DomainCell>> = grid
+                    .into_iter()
+                    .map(|row| row.cells.into_iter().map(DomainCell::try_from).collect())
+                    .collect::<Result<_, _>>()?;
+
+                let mut domain_players = HashMap::new();
+                for (key, player) in players {
+                    let player = crate::Player::try_from(player)?;
+                    if key != i32::from(player.id) {
+                        return Err(WireError::PlayerKeyMismatch { key, player_id: player.id });
+                    }
+                    // As regras indexam posições e cores por `id - 1`.
+                    if player.id == 0 || usize::from(player.id) > capacity {
+                        return Err(WireError::InvalidPlayerId { id: key });
+                    }
+                    if player.x >= columns || player.y >= rows {
+                        return Err(WireError::InvalidPosition {
+                            player_id: player.id,
+                            x: player.x as i32,
+                            y: player.y as i32,
+                        });
+                    }
+                    domain_players.insert(player.id, player);
+                }
+
+                let unknown_owner = grid.iter().flatten().find_map(|cell| match cell {
+                    DomainCell::Owned(owner) if !domain_players.contains_key(owner) => Some(*owner),
+                    _ => None,
+                });
+                if let Some(owner) = unknown_owner {
+                    return Err(WireError::UnknownOwner { owner });
+                }
+
+                Ok(crate::GameState {
+                    status: status.into(),
+                    width: columns,
+                    height: rows,
+                    grid,
+                    players: domain_players,
+                    min_players: threshold,
+                    max_players: capacity,
+                    version,
+                    epoch,
+                    palette: Vec::new(),
+                })
+            }
+        }
+    };
+}
+
+wire_conversions!(gamestate);
+wire_conversions!(gamemovement, current_turn: 0);
+
+impl From<Direction> for gamemovement::Direction {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Up => gamemovement::Direction::Up,
+            Direction::Down => gamemovement::Direction::Down,
+            Direction::Left => gamemovement::Direction::Left,
+            Direction::Right => gamemovement::Direction::Right,
+        }
+    }
+}
+
+impl From<gamemovement::Direction> for Direction {
+    fn from(direction: gamemovement::Direction) -> Self {
+        match direction {
+            gamemovement::Direction::Up => Direction::Up,
+            gamemovement::Direction::Down => Direction::Down,
+            gamemovement::Direction::Left => Direction::Left,
+            gamemovement::Direction::Right => Direction::Right,
+        }
+    }
+}
+
+impl From<crate::MovePayload> for MovePayload {
+    fn from(payload: crate::MovePayload) -> Self {
+        MovePayload { player_id: payload.player_id.into(), direction: payload.direction }
+    }
+}
+
+impl TryFrom<MovePayload> for crate::MovePayload {
+    type Error = WireError;
+
+    fn try_from(payload: MovePayload) -> Result<Self, WireError> {
+        Ok(crate::MovePayload { player_id: player_id(payload.player_id)?, direction: payload.direction })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::GameState;
+
+    fn running_game() -> GameState {
+        let mut game = crate::create_initial_state(4, 3, 2, 3);
+        game.join_player().unwrap();
+        game.join_player().unwrap();
+        game.apply_move(1, Direction::Left).unwrap();
+        game.version = 9;
+        game.epoch = 2;
+        game
+    }
+
+    fn assert_same_state(decoded: &GameState, game: &GameState) {
+        assert_eq!(
+            (decoded.status, decoded.width, decoded.height, decoded.version, decoded.epoch),
+            (game.status, game.width, game.height, game.version, game.epoch)
+        );
+        assert_eq!((decoded.min_players, decoded.max_players), (game.min_players, game.max_players));
+        assert_eq!(decoded.grid, game.grid);
+        for (id, player) in &game.players {
+            let other = &decoded.players[id];
+            assert_eq!((other.x, other.y, &other.color), (player.x, player.y, &player.color));
+        }
+        assert_eq!(decoded.players.len(), game.players.len());
+    }
+
+    #[test]
+    fn game_state_round_trips_through_both_packages() {
+        let game = running_game();
+        assert_same_state(&GameState::try_from(gamestate::GameState::from(&game)).unwrap(), &game);
+        assert_same_state(&GameState::try_from(gamemovement::GameState::from(&game)).unwrap(), &game);
+    }
+
+    #[test]
+    fn fields_missing_from_older_servers_decode_to_defaults() {
+        let mut wire = gamestate::GameState::from(&running_game());
+        (wire.version, wire.epoch, wire.min_players, wire.max_players) = (None, None, 0, 0);
+        let decoded = GameState::try_from(wire).unwrap();
+        assert_eq!((decoded.version, decoded.epoch), (0, 0));
+        assert_eq!((decoded.min_players, decoded.max_players), (MIN_PLAYERS_TO_START, MAX_PLAYERS));
+    }
+
+    #[test]
+    fn unsupported_fields_are_rejected() {
+        let mut wire = gamemovement::GameState::from(&running_game());
+        wire.current_turn = 2;
+        assert_eq!(
+            GameState::try_from(wire).unwrap_err(),
+            WireError::UnsupportedValue { field: "GameState.current_turn", value: 2 }
+        );
+    }
+
+    #[test]
+    fn player_limits_and_ids_are_validated() {
+        let mut wire = gamestate::GameState::from(&running_game());
+        wire.min_players = 4;
+        assert_eq!(
+            GameState::try_from(wire).unwrap_err(),
+            WireError::InvalidPlayerLimits { min_players: 4, max_players: 3 }
+        );
+
+        let mut wire = gamestate::GameState::from(&running_game());
+        let mut intruder = wire.players[&2].clone();
+        intruder.id = 4;
+        wire.players.insert(4, intruder);
+        assert_eq!(GameState::try_from(wire).unwrap_err(), WireError::InvalidPlayerId { id: 4 });
+
+        let mut wire = gamestate::GameState::from(&running_game());
+        let player = wire.players.remove(&2).unwrap();
+        wire.players.insert(3, player);
+        assert_eq!(GameState::try_from(wire).unwrap_err(), WireError::PlayerKeyMismatch { key: 3, player_id: 2 });
+    }
+
+    #[test]
+    fn cells_must_belong_to_players_in_the_game() {
+        let mut wire = gamestate::GameState::from(&running_game());
+        wire.players.remove(&2);
+        assert_eq!(GameState::try_from(wire).unwrap_err(), WireError::UnknownOwner { owner: 2 });
+    }
+
+    #[test]
+    fn grid_must_match_the_dimensions() {
+        let mut wire = gamestate::GameState::from(&running_game());
+        wire.width = 5;
+        assert_eq!(GameState::try_from(wire).unwrap_err(), WireError::GridMismatch { width: 5, height: 3 });
+
+        let mut wire = gamestate::GameState::from(&running_game());
+        wire.status = 7;
+        assert_eq!(
+            GameState::try_from(wire).unwrap_err(),
+            WireError::UnknownEnumValue { field: "GameState.status", value: 7 }
+        );
+    }
+}
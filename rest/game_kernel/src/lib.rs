@@ -1,5 +1,25 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::str::FromStr;
+
+mod delta;
+mod errors;
+#[cfg(feature = "grpc")]
+pub mod grpc;
+mod replay;
+mod rooms;
+mod rules;
+#[cfg(feature = "session")]
+mod session;
+mod strategy;
+pub use delta::*;
+pub use errors::*;
+pub use replay::*;
+pub use rooms::*;
+pub use rules::*;
+#[cfg(feature = "session")]
+pub use session::*;
+pub use strategy::*;
 
 pub type PlayerId = u8;
 
@@ -31,10 +51,84 @@ pub struct GameState {
     pub height: usize,
     pub grid: Vec<Vec<CellState>>,
     pub players: HashMap<PlayerId, Player>,
+    #[serde(default = "default_min_players")]
+    pub min_players: usize,
+    #[serde(default = "default_max_players")]
+    pub max_players: usize,
+    #[serde(default)]
+    pub version: u64,
+    #[serde(default)]
+    pub epoch: u32,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub palette: Vec<String>,
+}
+
+fn default_min_players() -> usize {
+    MIN_PLAYERS_TO_START
+}
+
+fn default_max_players() -> usize {
+    MAX_PLAYERS
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash, Debug)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [Direction::Up, Direction::Down, Direction::Left, Direction::Right];
+}
+
+impl std::fmt::Display for Direction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            Direction::Up => "UP",
+            Direction::Down => "DOWN",
+            Direction::Left => "LEFT",
+            Direction::Right => "RIGHT",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = MoveError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Direction::ALL
+            .into_iter()
+            .find(|direction| direction.to_string() == s)
+            .ok_or(MoveError::InvalidDirection)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct JoinResponse {
+    pub room_id: RoomId,
+    pub player: Player,
+    pub token: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Default, Debug)]
+#[serde(default)]
+pub struct RestartRequest {
+    pub keep_players: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct AddAiRequest {
+    pub difficulty: Difficulty,
+    #[serde(default)]
+    pub seed: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
 pub struct MovePayload {
     pub player_id: PlayerId,
-    pub direction: String,
+    pub direction: Direction,
 }
\ No newline at end of file
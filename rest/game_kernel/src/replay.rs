@@ -0,0 +1,252 @@
+use crate::*;
+use serde::{Deserialize, Serialize};
+
+pub const REPLAY_FORMAT_VERSION: u32 = 1;
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+#[serde(tag = "tipo", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GameEventKind {
+    Join { player_id: PlayerId, x: usize, y: usize },
+    Move { player_id: PlayerId, direction: Direction, x: usize, y: usize },
+    Status { status: GameStatus },
+    Restart { keep_players: bool },
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct GameEvent {
+    pub timestamp_ms: u64,
+    #[serde(flatten)]
+    pub kind: GameEventKind,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct ReplayExport {
+    pub format_version: u32,
+    pub room_id: RoomId,
+    pub width: usize,
+    pub height: usize,
+    #[serde(default = "default_min_players")]
+    pub min_players: usize,
+    pub max_players: usize,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub palette: Vec<String>,
+    pub events: Vec<GameEvent>,
+}
+
+// ===================================================================================
+// REGISTRO DE EVENTOS
+// ===================================================================================
+
+fn direction_between(from: (usize, usize), to: (usize, usize)) -> Option<Direction> {
+    match (to.0 as isize - from.0 as isize, to.1 as isize - from.1 as isize) {
+        (0, -1) => Some(Direction::Up),
+        (0, 1) => Some(Direction::Down),
+        (-1, 0) => Some(Direction::Left),
+        (1, 0) => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+pub fn transition_events(previous: &GameState, current: &GameState) -> Vec<GameEventKind> {
+    if previous.status == GameStatus::Finished && current.status != GameStatus::Finished {
+        return vec![GameEventKind::Restart { keep_players: !current.players.is_empty() }];
+    }
+
+    let mut players: Vec<_> = current.players.values().collect();
+    players.sort_by_key(|p| p.id);
+
+    let mut events = Vec::new();
+    for player in players {
+        match previous.players.get(&player.id) {
+            None => events.push(GameEventKind::Join { player_id: player.id, x: player.x, y: player.y }),
+            Some(old) if (old.x, old.y) != (player.x, player.y) => {
+                if let Some(direction) = direction_between((old.x, old.y), (player.x, player.y)) {
+                    events.push(GameEventKind::Move { player_id: player.id, direction, x: player.x, y: player.y });
+                }
+            }
+            Some(_) => {}
+        }
+    }
+
+    if previous.status != current.status {
+        events.push(GameEventKind::Status { status: current.status });
+    }
+    events
+}
+
+// ===================================================================================
+// REPRODUÇÃO DE PARTIDAS
+// ===================================================================================
+
+impl ReplayExport {
+    pub fn new(room_id: RoomId, game: &GameState, events: Vec<GameEvent>) -> Self {
+        ReplayExport {
+            format_version: REPLAY_FORMAT_VERSION,
+            room_id,
+            width: game.width,
+            height: game.height,
+            min_players: game.min_players,
+            max_players: game.max_players,
+            palette: game.palette.clone(),
+            events,
+        }
+    }
+
+    pub fn replay(&self) -> Result<Vec<GameState>, ReplayError> {
+        if self.format_version != REPLAY_FORMAT_VERSION {
+            return Err(ReplayError::UnsupportedVersion { format_version: self.format_version });
+        }
+
+        let mut state = create_initial_state(self.width, self.height, self.min_players, self.max_players);
+        state.palette = self.palette.clone();
+        let mut states = vec![state.clone()];
+
+        for (index, event) in self.events.iter().enumerate() {
+            let diverged = ReplayError::Diverged { event: index };
+            match event.kind {
+                GameEventKind::Join { player_id, x, y } => {
+                    let player = state.join_player().map_err(|_| diverged)?;
+                    if (player.id, player.x, player.y) != (player_id, x, y) {
+                        return Err(diverged);
+                    }
+                }
+                GameEventKind::Move { player_id, direction, x, y } => {
+                    state
+                        .apply_move(player_id, direction)
+                        .map_err(|reason| ReplayError::InvalidMove { event: index, reason })?;
+                    if state.players.get(&player_id).map(|p| (p.x, p.y)) != Some((x, y)) {
+                        return Err(diverged);
+                    }
+                }
+                GameEventKind::Status { status } => {
+                    if state.status != status {
+                        return Err(diverged);
+                    }
+                }
+                GameEventKind::Restart { keep_players } => {
+                    state.restart(keep_players).map_err(|_| diverged)?;
+                }
+            }
+            state.version = (index + 1) as u64;
+            states.push(state.clone());
+        }
+        Ok(states)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Reproduz o registro feito pelo Serviço B: cada transição do estado vivo vira eventos.
+    struct LiveRoom {
+        game: GameState,
+        events: Vec<GameEvent>,
+    }
+
+    impl LiveRoom {
+        fn new() -> Self {
+            let mut game = create_initial_state(3, 2, 2, 2);
+            game.palette = vec!["#111111".to_string(), "#222222".to_string()];
+            LiveRoom { game, events: Vec::new() }
+        }
+
+        fn apply(&mut self, change: impl FnOnce(&mut GameState)) {
+            let previous = self.game.clone();
+            change(&mut self.game);
+            let timestamp_ms = self.events.len() as u64;
+            self.events
+                .extend(transition_events(&previous, &self.game).into_iter().map(|kind| GameEvent { timestamp_ms, kind }));
+        }
+
+        fn join(&mut self) {
+            self.apply(|game| {
+                game.join_player().unwrap();
+            });
+        }
+
+        fn play(&mut self, moves: &[(PlayerId, Direction)]) {
+            for &(player_id, direction) in moves {
+                self.apply(|game| game.apply_move(player_id, direction).unwrap());
+            }
+        }
+
+        fn export(&self) -> ReplayExport {
+            ReplayExport::new(7, &self.game, self.events.clone())
+        }
+    }
+
+    const FULL_GAME: [(PlayerId, Direction); 6] = [
+        (1, Direction::Left), (2, Direction::Left),
+        (1, Direction::Right), (1, Direction::Right),
+        (2, Direction::Right), (2, Direction::Right),
+    ];
+
+    fn assert_matches_live(replayed: &GameState, live: &GameState) {
+        assert_eq!(replayed.status, live.status);
+        assert_eq!(replayed.grid, live.grid);
+        assert_eq!(replayed.epoch, live.epoch);
+        assert_eq!(replayed.players.len(), live.players.len());
+        for (id, player) in &live.players {
+            let other = &replayed.players[id];
+            assert_eq!((other.x, other.y, &other.color), (player.x, player.y, &player.color));
+        }
+    }
+
+    #[test]
+    fn replay_reaches_the_live_state() {
+        let mut room = LiveRoom::new();
+        room.join();
+        room.join();
+        room.play(&FULL_GAME);
+        assert_eq!(room.game.status, GameStatus::Finished);
+
+        room.apply(|game| game.restart(true).unwrap());
+        room.play(&[(1, Direction::Left), (2, Direction::Right)]);
+
+        let states = room.export().replay().unwrap();
+        assert_eq!(states.len(), room.events.len() + 1);
+        assert_matches_live(states.last().unwrap(), &room.game);
+    }
+
+    #[test]
+    fn replay_follows_a_restart_with_new_players() {
+        let mut room = LiveRoom::new();
+        room.join();
+        room.join();
+        room.play(&FULL_GAME);
+        room.apply(|game| game.restart(false).unwrap());
+        room.join();
+
+        let events: Vec<_> = room.events.iter().map(|event| event.kind).collect();
+        assert!(events.contains(&GameEventKind::Restart { keep_players: false }));
+
+        let export: ReplayExport = serde_json::from_str(&serde_json::to_string(&room.export()).unwrap()).unwrap();
+        assert_matches_live(export.replay().unwrap().last().unwrap(), &room.game);
+    }
+
+    #[test]
+    fn replay_reports_where_it_diverges() {
+        let mut room = LiveRoom::new();
+        room.join();
+        room.join();
+        room.play(&[(1, Direction::Left)]);
+        let moved = room.events.len() - 1;
+
+        let mut export = room.export();
+        export.events[moved].kind = GameEventKind::Move { player_id: 1, direction: Direction::Left, x: 2, y: 0 };
+        assert_eq!(export.replay().unwrap_err(), ReplayError::Diverged { event: moved });
+
+        export.events[moved].kind = GameEventKind::Move { player_id: 1, direction: Direction::Down, x: 1, y: 1 };
+        assert_eq!(
+            export.replay().unwrap_err(),
+            ReplayError::InvalidMove { event: moved, reason: MoveError::CellOwnedByOtherPlayer { owner: 2 } }
+        );
+
+        export.format_version = REPLAY_FORMAT_VERSION + 1;
+        assert_eq!(
+            export.replay().unwrap_err(),
+            ReplayError::UnsupportedVersion { format_version: REPLAY_FORMAT_VERSION + 1 }
+        );
+    }
+}
@@ -0,0 +1,115 @@
+use crate::*;
+use serde::{Deserialize, Serialize};
+
+pub type RoomId = u32;
+
+pub const DEFAULT_BOARD_SIZE: usize = 15;
+pub const MIN_BOARD_SIZE: usize = 3;
+pub const MAX_BOARD_SIZE: usize = 100;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(default)]
+pub struct RoomConfig {
+    pub width: usize,
+    pub height: usize,
+    pub min_players: usize,
+    pub max_players: usize,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub palette: Vec<String>,
+}
+
+impl Default for RoomConfig {
+    fn default() -> Self {
+        RoomConfig {
+            width: DEFAULT_BOARD_SIZE,
+            height: DEFAULT_BOARD_SIZE,
+            min_players: MIN_PLAYERS_TO_START,
+            max_players: MAX_PLAYERS,
+            palette: Vec::new(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct RoomLimits {
+    pub min_players: usize,
+    pub max_players: usize,
+}
+
+impl Default for RoomLimits {
+    fn default() -> Self {
+        RoomLimits { min_players: MIN_PLAYERS_TO_START, max_players: MAX_PLAYERS }
+    }
+}
+
+impl RoomLimits {
+    pub fn validate(&self) -> Result<(), RoomError> {
+        if self.min_players == 0 || self.min_players > self.max_players || self.max_players > MAX_PLAYERS {
+            return Err(RoomError::InvalidPlayerLimits { min_players: self.min_players, max_players: self.max_players });
+        }
+        Ok(())
+    }
+}
+
+pub fn is_hex_color(color: &str) -> bool {
+    color.len() == 7 && color.starts_with('#') && color[1..].chars().all(|c| c.is_ascii_hexdigit())
+}
+
+impl RoomConfig {
+    pub fn validate(&self) -> Result<(), RoomError> {
+        self.validate_within(&RoomLimits::default())
+    }
+
+    pub fn validate_within(&self, limits: &RoomLimits) -> Result<(), RoomError> {
+        let board_sizes = MIN_BOARD_SIZE..=MAX_BOARD_SIZE;
+        if !board_sizes.contains(&self.width) || !board_sizes.contains(&self.height) {
+            return Err(RoomError::InvalidDimensions { width: self.width, height: self.height });
+        }
+        if !(limits.min_players..=limits.max_players).contains(&self.max_players) {
+            return Err(RoomError::InvalidPlayerCount { max_players: self.max_players, limits: *limits });
+        }
+        if self.min_players == 0 || self.min_players > self.max_players {
+            return Err(RoomError::InvalidStartThreshold { min_players: self.min_players, max_players: self.max_players });
+        }
+        if let Some(color) = self.palette.iter().find(|color| !is_hex_color(color)) {
+            return Err(RoomError::InvalidColor { color: color.clone() });
+        }
+        Ok(())
+    }
+
+    pub fn initial_state(&self) -> GameState {
+        let mut state = create_initial_state(self.width, self.height, self.min_players, self.max_players);
+        state.palette = self.palette.clone();
+        state
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Debug)]
+pub struct RoomSummary {
+    pub id: RoomId,
+    pub width: usize,
+    pub height: usize,
+    pub players: usize,
+    #[serde(default = "default_min_players")]
+    pub min_players: usize,
+    pub max_players: usize,
+    pub status: GameStatus,
+}
+
+impl RoomSummary {
+    pub fn new(id: RoomId, game: &GameState) -> Self {
+        RoomSummary {
+            id,
+            width: game.width,
+            height: game.height,
+            players: game.players.len(),
+            min_players: game.min_players,
+            max_players: game.max_players,
+            status: game.status,
+        }
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.status == GameStatus::WaitingForPlayers && self.players < self.max_players
+    }
+}
@@ -0,0 +1,320 @@
+use crate::*;
+use std::collections::HashMap;
+
+// ===================================================================================
+// CONSTANTES DAS REGRAS
+// ===================================================================================
+
+pub const MIN_PLAYERS_TO_START: usize = 2;
+pub const MAX_PLAYERS: usize = 4;
+pub const PLAYER_COLORS: [&str; MAX_PLAYERS] = ["#FF5733", "#33C4FF", "#A2FF33", "#F733FF"];
+
+// ===================================================================================
+// REGRAS DO JOGO
+// ===================================================================================
+
+pub fn create_initial_state(width: usize, height: usize, min_players: usize, max_players: usize) -> GameState {
+    GameState {
+        status: GameStatus::WaitingForPlayers,
+        width,
+        height,
+        grid: vec![vec![CellState::Neutral; width]; height],
+        players: HashMap::new(),
+        min_players,
+        max_players,
+        version: 0,
+        epoch: 0,
+        palette: Vec::new(),
+    }
+}
+
+impl GameState {
+    fn spawn_position(&self, player_id: PlayerId) -> (usize, usize) {
+        let positions = [
+            (self.width / 2, 0), (self.width / 2, self.height - 1),
+            (0, self.height / 2), (self.width - 1, self.height / 2),
+        ];
+        positions[(player_id - 1) as usize]
+    }
+
+    pub fn player_color(&self, player_id: PlayerId) -> String {
+        let index = (player_id - 1) as usize;
+        match self.palette.get(index) {
+            Some(color) => color.clone(),
+            None => PLAYER_COLORS[index % MAX_PLAYERS].to_string(),
+        }
+    }
+
+    // A partida começa com `min_players`; até `max_players`, ainda é possível entrar com ela
+    // em andamento, desde que o ponto de partida do novo jogador esteja livre.
+    pub fn join_player(&mut self) -> Result<Player, JoinError> {
+        if self.status == GameStatus::Finished {
+            return Err(JoinError::GameAlreadyStarted);
+        }
+
+        if self.players.len() >= self.max_players.min(MAX_PLAYERS) {
+            return Err(JoinError::GameFull);
+        }
+        let next_player_id = (self.players.len() + 1) as PlayerId;
+
+        let (x, y) = self.spawn_position(next_player_id);
+        if self.status == GameStatus::InProgress && self.grid[y][x] != CellState::Neutral {
+            return Err(JoinError::GameAlreadyStarted);
+        }
+        let color = self.player_color(next_player_id);
+        let new_player = Player { id: next_player_id, x, y, color };
+
+        self.players.insert(next_player_id, new_player.clone());
+        self.grid[y][x] = CellState::Owned(next_player_id);
+
+        if self.status == GameStatus::WaitingForPlayers && self.players.len() >= self.min_players {
+            self.status = GameStatus::InProgress;
+        }
+
+        Ok(new_player)
+    }
+
+    pub fn resolve_move(&self, player_id: PlayerId, direction: Direction) -> Result<(usize, usize), MoveError> {
+        if self.status != GameStatus::InProgress {
+            return Err(MoveError::GameNotInProgress { status: self.status });
+        }
+
+        let player = self.players.get(&player_id).ok_or(MoveError::UnknownPlayer { player_id })?;
+
+        let (next_x, next_y) = match direction {
+            Direction::Up if player.y > 0 => (player.x, player.y - 1),
+            Direction::Down if player.y + 1 < self.height => (player.x, player.y + 1),
+            Direction::Left if player.x > 0 => (player.x - 1, player.y),
+            Direction::Right if player.x + 1 < self.width => (player.x + 1, player.y),
+            _ => return Err(MoveError::OutOfBounds { direction }),
+        };
+
+        match self.grid[next_y][next_x] {
+            CellState::Owned(owner) if owner != player_id => Err(MoveError::CellOwnedByOtherPlayer { owner }),
+            _ => Ok((next_x, next_y)),
+        }
+    }
+
+    pub fn apply_move(&mut self, player_id: PlayerId, direction: Direction) -> Result<(), MoveError> {
+        let (next_x, next_y) = self.resolve_move(player_id, direction)?;
+
+        self.grid[next_y][next_x] = CellState::Owned(player_id);
+        let player = self.players.get_mut(&player_id).ok_or(MoveError::UnknownPlayer { player_id })?;
+        player.x = next_x;
+        player.y = next_y;
+
+        self.check_game_end();
+        Ok(())
+    }
+
+    pub fn restart(&mut self, keep_players: bool) -> Result<(), RestartError> {
+        if self.status != GameStatus::Finished {
+            return Err(RestartError::GameNotFinished);
+        }
+
+        self.grid = vec![vec![CellState::Neutral; self.width]; self.height];
+
+        if !keep_players {
+            self.players.clear();
+            self.epoch += 1;
+            self.status = GameStatus::WaitingForPlayers;
+            return Ok(());
+        }
+
+        let mut ids: Vec<_> = self.players.keys().copied().collect();
+        ids.sort();
+        for id in ids {
+            let (x, y) = self.spawn_position(id);
+            if let Some(player) = self.players.get_mut(&id) {
+                player.x = x;
+                player.y = y;
+            }
+            self.grid[y][x] = CellState::Owned(id);
+        }
+        self.status = if self.players.len() >= self.min_players {
+            GameStatus::InProgress
+        } else {
+            GameStatus::WaitingForPlayers
+        };
+        Ok(())
+    }
+
+    pub fn has_neutral_cells(&self) -> bool {
+        self.grid.iter().flatten().any(|&cell| cell == CellState::Neutral)
+    }
+
+    pub fn check_game_end(&mut self) -> bool {
+        if self.status == GameStatus::InProgress && !self.has_neutral_cells() {
+            self.status = GameStatus::Finished;
+        }
+        self.status == GameStatus::Finished
+    }
+
+    pub fn score_of(&self, player_id: PlayerId) -> usize {
+        self.grid.iter().flatten().filter(|&&cell| cell == CellState::Owned(player_id)).count()
+    }
+
+    pub fn scores(&self) -> HashMap<PlayerId, usize> {
+        self.players.keys().map(|&id| (id, self.score_of(id))).collect()
+    }
+
+    pub fn ranking(&self) -> Vec<(&Player, usize)> {
+        let mut ranking: Vec<_> = self.players.values().map(|p| (p, self.score_of(p.id))).collect();
+        ranking.sort_by_key(|&(p, score)| (std::cmp::Reverse(score), p.id));
+        ranking
+    }
+
+    pub fn winner(&self) -> Option<PlayerId> {
+        if self.status != GameStatus::Finished {
+            return None;
+        }
+        self.ranking().first().map(|(p, _)| p.id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Mapa 3x2: o Jogador 1 nasce em (1, 0) e o Jogador 2 em (1, 1).
+    fn two_player_game() -> GameState {
+        let mut game = create_initial_state(3, 2, 2, 2);
+        game.join_player().unwrap();
+        game.join_player().unwrap();
+        game
+    }
+
+    fn play(game: &mut GameState, moves: &[(PlayerId, Direction)]) {
+        for &(player_id, direction) in moves {
+            game.apply_move(player_id, direction).unwrap();
+        }
+    }
+
+    fn finished_game() -> GameState {
+        let mut game = two_player_game();
+        play(&mut game, &[
+            (1, Direction::Left), (2, Direction::Left),
+            (1, Direction::Right), (1, Direction::Right),
+            (2, Direction::Right), (2, Direction::Right),
+        ]);
+        game
+    }
+
+    #[test]
+    fn game_starts_once_min_players_join() {
+        let mut game = create_initial_state(5, 5, 2, 4);
+
+        let first = game.join_player().unwrap();
+        assert_eq!((first.id, first.x, first.y), (1, 2, 0));
+        assert_eq!(game.status, GameStatus::WaitingForPlayers);
+
+        let second = game.join_player().unwrap();
+        assert_eq!((second.id, second.x, second.y), (2, 2, 4));
+        assert_eq!(game.status, GameStatus::InProgress);
+        assert_eq!(game.grid[4][2], CellState::Owned(2));
+    }
+
+    #[test]
+    fn late_join_needs_a_free_spawn() {
+        let mut game = create_initial_state(3, 3, 2, 4);
+        game.join_player().unwrap();
+        game.join_player().unwrap();
+
+        let third = game.join_player().unwrap();
+        assert_eq!((third.x, third.y), (0, 1));
+        assert_eq!(game.status, GameStatus::InProgress);
+
+        play(&mut game, &[(1, Direction::Right), (1, Direction::Down)]);
+        assert_eq!(game.join_player().unwrap_err(), JoinError::GameAlreadyStarted);
+        assert_eq!(game.players.len(), 3);
+    }
+
+    #[test]
+    fn join_rejects_full_and_finished_games() {
+        let mut game = two_player_game();
+        assert_eq!(game.join_player().unwrap_err(), JoinError::GameFull);
+
+        let mut game = finished_game();
+        game.players.remove(&2);
+        assert_eq!(game.join_player().unwrap_err(), JoinError::GameAlreadyStarted);
+    }
+
+    #[test]
+    fn moves_are_rejected_outside_a_running_game() {
+        let mut game = create_initial_state(3, 2, 2, 2);
+        game.join_player().unwrap();
+        assert_eq!(
+            game.apply_move(1, Direction::Left),
+            Err(MoveError::GameNotInProgress { status: GameStatus::WaitingForPlayers })
+        );
+
+        let mut game = finished_game();
+        assert_eq!(
+            game.apply_move(1, Direction::Down),
+            Err(MoveError::GameNotInProgress { status: GameStatus::Finished })
+        );
+    }
+
+    #[test]
+    fn moves_validate_player_and_bounds() {
+        let mut game = two_player_game();
+        assert_eq!(game.apply_move(3, Direction::Left), Err(MoveError::UnknownPlayer { player_id: 3 }));
+        assert_eq!(game.apply_move(1, Direction::Up), Err(MoveError::OutOfBounds { direction: Direction::Up }));
+        assert_eq!(game.apply_move(2, Direction::Down), Err(MoveError::OutOfBounds { direction: Direction::Down }));
+
+        play(&mut game, &[(1, Direction::Right)]);
+        assert_eq!(game.apply_move(1, Direction::Right), Err(MoveError::OutOfBounds { direction: Direction::Right }));
+        assert_eq!(game.players[&1].x, 2);
+    }
+
+    #[test]
+    fn cells_of_other_players_are_blocked() {
+        let mut game = two_player_game();
+        assert_eq!(game.apply_move(1, Direction::Down), Err(MoveError::CellOwnedByOtherPlayer { owner: 2 }));
+
+        play(&mut game, &[(1, Direction::Left), (1, Direction::Right)]);
+        assert_eq!((game.players[&1].x, game.players[&1].y), (1, 0));
+        assert_eq!(game.score_of(1), 2);
+    }
+
+    #[test]
+    fn game_finishes_when_no_neutral_cell_is_left() {
+        let game = finished_game();
+        assert_eq!(game.status, GameStatus::Finished);
+        assert!(!game.has_neutral_cells());
+        assert_eq!(game.scores(), HashMap::from([(1, 3), (2, 3)]));
+        assert_eq!(game.winner(), Some(1));
+    }
+
+    #[test]
+    fn restart_only_after_the_game_finishes() {
+        let mut game = two_player_game();
+        assert_eq!(game.restart(true), Err(RestartError::GameNotFinished));
+
+        let mut game = finished_game();
+        game.restart(true).unwrap();
+        assert_eq!(game.status, GameStatus::InProgress);
+        assert_eq!(game.epoch, 0);
+        assert_eq!((game.players[&1].x, game.players[&1].y), (1, 0));
+        assert_eq!(game.scores(), HashMap::from([(1, 1), (2, 1)]));
+    }
+
+    #[test]
+    fn restart_without_players_starts_a_new_epoch() {
+        let mut game = finished_game();
+        game.restart(false).unwrap();
+        assert_eq!(game.status, GameStatus::WaitingForPlayers);
+        assert!(game.players.is_empty());
+        assert!(!game.grid.iter().flatten().any(|&cell| cell != CellState::Neutral));
+        assert_eq!(game.epoch, 1);
+    }
+
+    #[test]
+    fn states_saved_without_limits_get_the_default_threshold_and_capacity() {
+        let mut saved = serde_json::to_value(two_player_game()).unwrap();
+        saved.as_object_mut().unwrap().retain(|key, _| key != "min_players" && key != "max_players");
+        let game: GameState = serde_json::from_value(saved).unwrap();
+        assert_eq!((game.min_players, game.max_players), (MIN_PLAYERS_TO_START, MAX_PLAYERS));
+        assert_eq!(RoomConfig::default().max_players, MAX_PLAYERS);
+    }
+}
@@ -0,0 +1,141 @@
+use crate::*;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+pub const SESSION_SECRET_ENV: &str = "SESSION_SECRET";
+const DEV_SESSION_SECRET: &str = "pspd-segredo-de-desenvolvimento";
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct SessionClaims {
+    pub room_id: RoomId,
+    pub player_id: PlayerId,
+    pub epoch: u32,
+}
+
+// Sem SESSION_SECRET, o segredo fixo só é aceito quando o serviço foi iniciado em modo de
+// desenvolvimento; nos demais casos a inicialização deve falhar.
+pub fn load_session_secret(allow_dev_secret: bool) -> Option<Vec<u8>> {
+    match std::env::var(SESSION_SECRET_ENV) {
+        Ok(secret) if !secret.is_empty() => Some(secret.into_bytes()),
+        _ if allow_dev_secret => {
+            tracing::warn!("{} não definido; usando o segredo de desenvolvimento", SESSION_SECRET_ENV);
+            Some(DEV_SESSION_SECRET.as_bytes().to_vec())
+        }
+        _ => {
+            tracing::error!(
+                "{} não definido; defina o segredo ou use --dev-session-secret apenas em desenvolvimento",
+                SESSION_SECRET_ENV
+            );
+            None
+        }
+    }
+}
+
+fn signature(secret: &[u8], payload: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC aceita chaves de qualquer tamanho");
+    mac.update(payload.as_bytes());
+    mac
+}
+
+fn decode_hex(value: &str) -> Option<Vec<u8>> {
+    if !value.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..value.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(value.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+pub fn issue_session_token(secret: &[u8], claims: SessionClaims, nonce: u64) -> String {
+    let payload = format!("{}.{}.{}.{:016x}", claims.room_id, claims.player_id, claims.epoch, nonce);
+    let tag = signature(secret, &payload).finalize().into_bytes();
+    let tag_hex: String = tag.iter().map(|byte| format!("{:02x}", byte)).collect();
+    format!("{}.{}", payload, tag_hex)
+}
+
+pub fn verify_session_token(secret: &[u8], token: &str) -> Result<SessionClaims, SessionError> {
+    let (payload, tag_hex) = token.rsplit_once('.').ok_or(SessionError::MalformedToken)?;
+    let tag = decode_hex(tag_hex).ok_or(SessionError::MalformedToken)?;
+
+    signature(secret, payload)
+        .verify_slice(&tag)
+        .map_err(|_| SessionError::InvalidSignature)?;
+
+    let mut parts = payload.split('.');
+    let room_id = parts.next().and_then(|part| part.parse().ok()).ok_or(SessionError::MalformedToken)?;
+    let player_id = parts.next().and_then(|part| part.parse().ok()).ok_or(SessionError::MalformedToken)?;
+    let epoch = parts.next().and_then(|part| part.parse().ok()).ok_or(SessionError::MalformedToken)?;
+    Ok(SessionClaims { room_id, player_id, epoch })
+}
+
+impl SessionClaims {
+    pub fn authorize(&self, room_id: RoomId, player_id: PlayerId) -> Result<(), SessionError> {
+        if self.room_id != room_id {
+            return Err(SessionError::WrongRoom);
+        }
+        if self.player_id != player_id {
+            return Err(SessionError::WrongPlayer);
+        }
+        Ok(())
+    }
+
+    pub fn check_epoch(&self, game: &GameState) -> Result<(), SessionError> {
+        if self.epoch != game.epoch {
+            return Err(SessionError::StaleSession);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &[u8] = b"segredo-de-teste";
+    const CLAIMS: SessionClaims = SessionClaims { room_id: 3, player_id: 2, epoch: 5 };
+
+    #[test]
+    fn tokens_round_trip_their_claims() {
+        let token = issue_session_token(SECRET, CLAIMS, 42);
+        assert_eq!(verify_session_token(SECRET, &token), Ok(CLAIMS));
+        assert_ne!(token, issue_session_token(SECRET, CLAIMS, 43));
+    }
+
+    #[test]
+    fn tampered_or_foreign_tokens_are_rejected() {
+        let token = issue_session_token(SECRET, CLAIMS, 42);
+        assert_eq!(verify_session_token(b"outro-segredo", &token), Err(SessionError::InvalidSignature));
+
+        let forged = token.replacen("3.2.", "3.1.", 1);
+        assert_eq!(verify_session_token(SECRET, &forged), Err(SessionError::InvalidSignature));
+
+        assert_eq!(verify_session_token(SECRET, "sem-assinatura"), Err(SessionError::MalformedToken));
+        assert_eq!(verify_session_token(SECRET, "3.2.5.0.zz"), Err(SessionError::MalformedToken));
+    }
+
+    #[test]
+    fn claims_are_bound_to_room_and_player() {
+        assert_eq!(CLAIMS.authorize(3, 2), Ok(()));
+        assert_eq!(CLAIMS.authorize(4, 2), Err(SessionError::WrongRoom));
+        assert_eq!(CLAIMS.authorize(3, 1), Err(SessionError::WrongPlayer));
+    }
+
+    #[test]
+    fn tokens_from_an_earlier_epoch_are_stale() {
+        let mut game = create_initial_state(1, 2, 2, 2);
+        game.join_player().unwrap();
+        game.join_player().unwrap();
+        assert_eq!(game.status, GameStatus::InProgress);
+        game.check_game_end();
+
+        let claims = SessionClaims { room_id: 1, player_id: 1, epoch: game.epoch };
+        let token = issue_session_token(SECRET, claims, 0);
+        assert_eq!(verify_session_token(SECRET, &token).unwrap().check_epoch(&game), Ok(()));
+
+        game.restart(false).unwrap();
+        assert_eq!(verify_session_token(SECRET, &token).unwrap().check_epoch(&game), Err(SessionError::StaleSession));
+    }
+}
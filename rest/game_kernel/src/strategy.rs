@@ -0,0 +1,369 @@
+use crate::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fmt;
+use std::str::FromStr;
+
+// ===================================================================================
+// GERADOR PSEUDOALEATÓRIO (SPLITMIX64)
+// ===================================================================================
+
+#[derive(Clone, Debug)]
+pub struct SimpleRng {
+    state: u64,
+}
+
+impl SimpleRng {
+    pub fn new(seed: u64) -> Self {
+        SimpleRng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    pub fn below(&mut self, bound: usize) -> usize {
+        (self.next_u64() % bound as u64) as usize
+    }
+}
+
+// ===================================================================================
+// ESTRATÉGIAS
+// ===================================================================================
+
+pub trait Strategy {
+    fn name(&self) -> &'static str;
+    fn choose_move(&mut self, state: &GameState, player_id: PlayerId) -> Option<Direction>;
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum StrategyKind {
+    RandomWalk,
+    Greedy,
+    FloodFill,
+}
+
+impl StrategyKind {
+    pub const ALL: [StrategyKind; 3] = [StrategyKind::RandomWalk, StrategyKind::Greedy, StrategyKind::FloodFill];
+
+    pub fn build(self, seed: u64) -> Box<dyn Strategy + Send> {
+        match self {
+            StrategyKind::RandomWalk => Box::new(RandomWalk { rng: SimpleRng::new(seed) }),
+            StrategyKind::Greedy => Box::new(GreedyNearestNeutral),
+            StrategyKind::FloodFill => Box::new(FloodFillTerritory),
+        }
+    }
+}
+
+impl fmt::Display for StrategyKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            StrategyKind::RandomWalk => "random",
+            StrategyKind::Greedy => "greedy",
+            StrategyKind::FloodFill => "flood-fill",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for StrategyKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        StrategyKind::ALL
+            .into_iter()
+            .find(|kind| kind.to_string() == s)
+            .ok_or_else(|| format!("Estratégia desconhecida '{}' (use random, greedy ou flood-fill)", s))
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum Difficulty {
+    Easy,
+    Medium,
+    Hard,
+}
+
+impl Difficulty {
+    pub const ALL: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+    const EASY_MISTAKE_PERCENT: usize = 35;
+
+    pub fn build(self, seed: u64) -> Box<dyn Strategy + Send> {
+        match self {
+            Difficulty::Easy => Box::new(Erratic {
+                rng: SimpleRng::new(seed),
+                mistake_percent: Self::EASY_MISTAKE_PERCENT,
+                planner: GreedyNearestNeutral,
+            }),
+            Difficulty::Medium => StrategyKind::Greedy.build(seed),
+            Difficulty::Hard => StrategyKind::FloodFill.build(seed),
+        }
+    }
+}
+
+impl fmt::Display for Difficulty {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Difficulty::Easy => "easy",
+            Difficulty::Medium => "medium",
+            Difficulty::Hard => "hard",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Difficulty::ALL
+            .into_iter()
+            .find(|difficulty| difficulty.to_string() == s)
+            .ok_or_else(|| format!("Dificuldade desconhecida '{}' (use easy, medium ou hard)", s))
+    }
+}
+
+fn legal_moves(state: &GameState, player_id: PlayerId) -> Vec<(Direction, (usize, usize))> {
+    Direction::ALL
+        .into_iter()
+        .filter_map(|direction| state.resolve_move(player_id, direction).ok().map(|target| (direction, target)))
+        .collect()
+}
+
+fn neighbors(state: &GameState, (x, y): (usize, usize)) -> impl Iterator<Item = (usize, usize)> {
+    let (width, height) = (state.width, state.height);
+    [
+        (y > 0).then(|| (x, y - 1)),
+        (y + 1 < height).then(|| (x, y + 1)),
+        (x > 0).then(|| (x - 1, y)),
+        (x + 1 < width).then(|| (x + 1, y)),
+    ]
+    .into_iter()
+    .flatten()
+}
+
+fn passable(state: &GameState, player_id: PlayerId, (x, y): (usize, usize)) -> bool {
+    match state.grid[y][x] {
+        CellState::Neutral => true,
+        CellState::Owned(owner) => owner == player_id,
+    }
+}
+
+fn distances_from(state: &GameState, player_id: PlayerId, start: (usize, usize)) -> Vec<Vec<Option<usize>>> {
+    let mut distances = vec![vec![None; state.width]; state.height];
+    distances[start.1][start.0] = Some(0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(cell) = queue.pop_front() {
+        let distance = distances[cell.1][cell.0].unwrap_or_default();
+        for next in neighbors(state, cell) {
+            if distances[next.1][next.0].is_none() && passable(state, player_id, next) {
+                distances[next.1][next.0] = Some(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+    distances
+}
+
+pub struct RandomWalk {
+    rng: SimpleRng,
+}
+
+impl Strategy for RandomWalk {
+    fn name(&self) -> &'static str {
+        "random"
+    }
+
+    fn choose_move(&mut self, state: &GameState, player_id: PlayerId) -> Option<Direction> {
+        let moves = legal_moves(state, player_id);
+        if moves.is_empty() {
+            return None;
+        }
+        Some(moves[self.rng.below(moves.len())].0)
+    }
+}
+
+pub struct Erratic<S> {
+    rng: SimpleRng,
+    mistake_percent: usize,
+    planner: S,
+}
+
+impl<S: Strategy> Strategy for Erratic<S> {
+    fn name(&self) -> &'static str {
+        "erratic"
+    }
+
+    fn choose_move(&mut self, state: &GameState, player_id: PlayerId) -> Option<Direction> {
+        let moves = legal_moves(state, player_id);
+        if !moves.is_empty() && self.rng.below(100) < self.mistake_percent {
+            return Some(moves[self.rng.below(moves.len())].0);
+        }
+        self.planner.choose_move(state, player_id)
+    }
+}
+
+pub struct GreedyNearestNeutral;
+
+impl Strategy for GreedyNearestNeutral {
+    fn name(&self) -> &'static str {
+        "greedy"
+    }
+
+    fn choose_move(&mut self, state: &GameState, player_id: PlayerId) -> Option<Direction> {
+        legal_moves(state, player_id)
+            .into_iter()
+            .filter_map(|(direction, target)| {
+                if state.grid[target.1][target.0] == CellState::Neutral {
+                    return Some((0, direction));
+                }
+                let distances = distances_from(state, player_id, target);
+                state
+                    .grid
+                    .iter()
+                    .enumerate()
+                    .flat_map(|(y, row)| row.iter().enumerate().map(move |(x, &cell)| (x, y, cell)))
+                    .filter(|&(_, _, cell)| cell == CellState::Neutral)
+                    .filter_map(|(x, y, _)| distances[y][x])
+                    .min()
+                    .map(|distance| (distance, direction))
+            })
+            .min_by_key(|&(distance, _)| distance)
+            .map(|(_, direction)| direction)
+    }
+}
+
+pub struct FloodFillTerritory;
+
+impl FloodFillTerritory {
+    fn evaluate(state: &GameState, player_id: PlayerId, start: (usize, usize)) -> (usize, Option<usize>) {
+        let mine = distances_from(state, player_id, start);
+        let rivals: Vec<_> = state
+            .players
+            .values()
+            .filter(|p| p.id != player_id)
+            .map(|p| distances_from(state, p.id, (p.x, p.y)))
+            .collect();
+
+        let mut share = 0;
+        let mut nearest = None;
+        for (y, row) in state.grid.iter().enumerate() {
+            for (x, &cell) in row.iter().enumerate() {
+                let Some(my_distance) = mine[y][x] else { continue };
+                if cell != CellState::Neutral {
+                    continue;
+                }
+                nearest = Some(nearest.map_or(my_distance, |n: usize| n.min(my_distance)));
+                let closest_rival = rivals.iter().filter_map(|d| d[y][x]).min();
+                if closest_rival.is_none_or(|rival| my_distance < rival) {
+                    share += 1;
+                }
+            }
+        }
+        (share, nearest)
+    }
+}
+
+impl Strategy for FloodFillTerritory {
+    fn name(&self) -> &'static str {
+        "flood-fill"
+    }
+
+    fn choose_move(&mut self, state: &GameState, player_id: PlayerId) -> Option<Direction> {
+        legal_moves(state, player_id)
+            .into_iter()
+            .rev()
+            .filter_map(|(direction, target)| {
+                let (share, nearest) = Self::evaluate(state, player_id, target);
+                Some(((share, std::cmp::Reverse(nearest?)), direction))
+            })
+            .max_by_key(|&(score, _)| score)
+            .map(|(_, direction)| direction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn two_player_game(width: usize, height: usize) -> GameState {
+        let mut game = create_initial_state(width, height, 2, 2);
+        game.join_player().unwrap();
+        game.join_player().unwrap();
+        game
+    }
+
+    fn all_strategies(seed: u64) -> Vec<Box<dyn Strategy + Send>> {
+        StrategyKind::ALL
+            .into_iter()
+            .map(|kind| kind.build(seed))
+            .chain(Difficulty::ALL.into_iter().map(|difficulty| difficulty.build(seed)))
+            .collect()
+    }
+
+    // Joga uma partida com a mesma estratégia nos dois jogadores, conferindo cada jogada
+    // com o `resolve_move`, e devolve a sequência de movimentos.
+    fn play(build: impl Fn() -> Box<dyn Strategy + Send>, width: usize, height: usize) -> Vec<(PlayerId, Direction)> {
+        let mut game = two_player_game(width, height);
+        let mut strategies = [build(), build()];
+        let mut moves = Vec::new();
+        while game.status == GameStatus::InProgress {
+            let mut moved = false;
+            for (player_id, strategy) in (1..).zip(strategies.iter_mut()) {
+                let has_legal_move = !legal_moves(&game, player_id).is_empty();
+                let Some(direction) = strategy.choose_move(&game, player_id) else { continue };
+                assert!(has_legal_move, "{} escolheu {} sem jogada legal", strategy.name(), direction);
+                game.resolve_move(player_id, direction)
+                    .unwrap_or_else(|err| panic!("{} escolheu {}: {}", strategy.name(), direction, err));
+                game.apply_move(player_id, direction).unwrap();
+                game.check_game_end();
+                moves.push((player_id, direction));
+                moved = true;
+            }
+            if !moved {
+                break;
+            }
+        }
+        moves
+    }
+
+    #[test]
+    fn same_seed_and_state_give_the_same_moves() {
+        for index in 0..all_strategies(0).len() {
+            let build = |seed| move || all_strategies(seed).swap_remove(index);
+            assert_eq!(play(build(7), 6, 5), play(build(7), 6, 5));
+        }
+    }
+
+    #[test]
+    fn strategies_only_choose_legal_moves() {
+        for seed in 0..20 {
+            for index in 0..all_strategies(seed).len() {
+                play(move || all_strategies(seed).swap_remove(index), 5 + (seed as usize % 3), 4);
+            }
+        }
+    }
+
+    #[test]
+    fn greedy_heads_for_the_nearest_neutral_cell() {
+        // Jogador 1 em (2, 0) e jogador 2 em (2, 2); só (4, 0) e (0, 1) continuam neutras.
+        let mut game = two_player_game(5, 3);
+        for (y, row) in game.grid.iter_mut().enumerate() {
+            for (x, cell) in row.iter_mut().enumerate() {
+                if *cell == CellState::Neutral && (x, y) != (4, 0) && (x, y) != (0, 1) {
+                    *cell = CellState::Owned(1);
+                }
+            }
+        }
+        assert_eq!(GreedyNearestNeutral.choose_move(&game, 1), Some(Direction::Right));
+
+        game.apply_move(1, Direction::Right).unwrap();
+        assert_eq!(GreedyNearestNeutral.choose_move(&game, 1), Some(Direction::Right));
+    }
+}
@@ -0,0 +1,355 @@
+use clap::Parser;
+use game_kernel::*;
+use reqwest::{Client, RequestBuilder, StatusCode};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::time::{Instant, Interval, MissedTickBehavior};
+
+mod stats;
+
+use stats::{logged_moves, ClientStats, Report};
+
+// ===================================================================================
+// CONFIGURAÇÃO
+// ===================================================================================
+
+#[derive(Parser, Serialize, Clone)]
+#[command(name = "load_gen", about = "Gerador de carga para o backend REST do jogo")]
+struct Args {
+    /// URL do gateway (ou do Serviço B)
+    #[arg(long, default_value = "http://localhost:8000")]
+    server: String,
+
+    /// URL usada para enviar movimentos (padrão: a mesma de --server)
+    #[arg(long)]
+    move_server: Option<String>,
+
+    /// Número de clientes simulados
+    #[arg(long, default_value_t = 20)]
+    clients: usize,
+
+    /// Jogadores por sala criada pelo gerador
+    #[arg(long, default_value_t = MAX_PLAYERS)]
+    room_size: usize,
+
+    /// Largura e altura do mapa das salas criadas
+    #[arg(long, default_value_t = DEFAULT_BOARD_SIZE)]
+    board_size: usize,
+
+    /// Salas existentes a usar em vez de criar novas (separadas por vírgula)
+    #[arg(long, value_delimiter = ',')]
+    rooms: Vec<RoomId>,
+
+    /// Duração do teste em segundos
+    #[arg(long, default_value_t = 30)]
+    duration_secs: u64,
+
+    /// Consultas de estado por segundo, por cliente
+    #[arg(long, default_value_t = 5.0)]
+    poll_rate: f64,
+
+    /// Movimentos por segundo, por cliente
+    #[arg(long, default_value_t = 2.0)]
+    move_rate: f64,
+
+    /// Tentativas extras de entrada por segundo, por cliente (além da entrada inicial)
+    #[arg(long, default_value_t = 0.0)]
+    join_rate: f64,
+
+    /// Semente dos movimentos aleatórios
+    #[arg(long, default_value_t = 1)]
+    seed: u64,
+
+    /// Arquivo JSON para exportar os resultados
+    #[arg(long)]
+    output: Option<PathBuf>,
+}
+
+struct Context {
+    client: Client,
+    server_url: String,
+    move_url: String,
+    args: Args,
+    deadline: Instant,
+}
+
+impl Context {
+    fn room_url(&self, room_id: RoomId, path: &str) -> String {
+        format!("{}/game/{}/{}", self.server_url, room_id, path)
+    }
+}
+
+// ===================================================================================
+// FUNÇÃO PRINCIPAL
+// ===================================================================================
+
+#[tokio::main]
+async fn main() {
+    let args = Args::parse();
+    if args.clients == 0 || !(MIN_PLAYERS_TO_START..=MAX_PLAYERS).contains(&args.room_size) {
+        eprintln!("Use pelo menos 1 cliente e salas de {} a {} jogadores", MIN_PLAYERS_TO_START, MAX_PLAYERS);
+        return;
+    }
+
+    let server_url = args.server.trim_end_matches('/').to_string();
+    let move_url = args.move_server.as_deref().map_or(server_url.clone(), |url| url.trim_end_matches('/').to_string());
+    let mut setup = ClientStats::default();
+    let client = Client::new();
+
+    let rooms = if args.rooms.is_empty() {
+        match create_rooms(&client, &server_url, &args, &mut setup).await {
+            Ok(rooms) => rooms,
+            Err(e) => {
+                eprintln!("Não foi possível criar as salas: {}", e);
+                return;
+            }
+        }
+    } else {
+        args.rooms.clone()
+    };
+    let baseline: HashMap<_, _> = fetch_events(&client, &server_url, &rooms)
+        .await
+        .into_iter()
+        .map(|(room_id, events)| (room_id, events.len()))
+        .collect();
+
+    println!(
+        "Iniciando {} clientes em {} sala(s) por {}s ({} consultas/s, {} movimentos/s por cliente)",
+        args.clients, rooms.len(), args.duration_secs, args.poll_rate, args.move_rate
+    );
+
+    let started_at = Instant::now();
+    let context = Arc::new(Context {
+        client,
+        server_url,
+        move_url,
+        deadline: started_at + Duration::from_secs(args.duration_secs),
+        args: args.clone(),
+    });
+
+    let handles: Vec<_> = (0..args.clients)
+        .map(|index| {
+            let room_id = rooms[(index / args.room_size) % rooms.len()];
+            tokio::spawn(run_client(context.clone(), index, room_id))
+        })
+        .collect();
+
+    let mut total = setup;
+    let mut seats = Vec::new();
+    for handle in handles {
+        match handle.await {
+            Ok((stats, seat)) => {
+                total.merge(stats);
+                seats.extend(seat);
+            }
+            Err(e) => eprintln!("Cliente simulado falhou: {}", e),
+        }
+    }
+    let elapsed = started_at.elapsed();
+
+    let events = fetch_events(&context.client, &context.server_url, &rooms).await;
+    let logged = seats
+        .iter()
+        .map(|(room_id, player_id)| {
+            let skip = baseline.get(room_id).copied().unwrap_or(0);
+            let room_events = events.get(room_id).and_then(|events| events.get(skip..)).unwrap_or_default();
+            logged_moves(room_events, *player_id)
+        })
+        .sum();
+
+    let report = Report::new(args, total, logged, elapsed);
+    report.print();
+
+    if let Some(path) = &report.config.output {
+        match serde_json::to_vec_pretty(&report).map(|json| std::fs::write(path, json)) {
+            Ok(Ok(())) => println!("Resultados exportados para {}", path.display()),
+            Ok(Err(e)) => eprintln!("Erro ao gravar {}: {}", path.display(), e),
+            Err(e) => eprintln!("Erro ao serializar resultados: {}", e),
+        }
+    }
+}
+
+async fn create_rooms(client: &Client, server_url: &str, args: &Args, stats: &mut ClientStats) -> Result<Vec<RoomId>, String> {
+    let config = RoomConfig {
+        width: args.board_size,
+        height: args.board_size,
+        min_players: args.room_size,
+        max_players: args.room_size,
+        ..RoomConfig::default()
+    };
+    let count = args.clients.div_ceil(args.room_size);
+
+    let mut rooms = Vec::with_capacity(count);
+    for _ in 0..count {
+        let request = client.post(format!("{}/game/rooms", server_url)).json(&config);
+        let (status, body) = timed(stats, "rooms", request).await.ok_or("erro de rede")?;
+        if !status.is_success() {
+            return Err(String::from_utf8_lossy(&body).into_owned());
+        }
+        let summary: RoomSummary = serde_json::from_slice(&body).map_err(|e| e.to_string())?;
+        rooms.push(summary.id);
+    }
+    Ok(rooms)
+}
+
+async fn fetch_events(client: &Client, server_url: &str, rooms: &[RoomId]) -> HashMap<RoomId, Vec<GameEvent>> {
+    let mut events = HashMap::new();
+    for &room_id in rooms {
+        let url = format!("{}/game/{}/replay", server_url, room_id);
+        let Ok(export) = async { client.get(url).send().await?.error_for_status()?.json::<ReplayExport>().await }.await else {
+            eprintln!("Replay da sala {} indisponível; movimentos perdidos não serão contabilizados", room_id);
+            continue;
+        };
+        events.insert(room_id, export.events);
+    }
+    events
+}
+
+// ===================================================================================
+// CLIENTE SIMULADO
+// ===================================================================================
+
+async fn timed(stats: &mut ClientStats, endpoint: &'static str, request: RequestBuilder) -> Option<(StatusCode, Vec<u8>)> {
+    let start = Instant::now();
+    let result = async {
+        let resp = request.send().await?;
+        let status = resp.status();
+        Ok::<_, reqwest::Error>((status, resp.bytes().await?.to_vec()))
+    }
+    .await;
+
+    stats.record(endpoint, start.elapsed(), result.as_ref().ok().map(|(status, _)| status.as_u16()));
+    result.ok()
+}
+
+fn rate_interval(rate: f64, offset: Duration) -> Option<Interval> {
+    if rate <= 0.0 {
+        return None;
+    }
+    let period = Duration::from_secs_f64(1.0 / rate);
+    let mut interval = tokio::time::interval_at(Instant::now() + offset.min(period), period);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    Some(interval)
+}
+
+async fn tick(interval: &mut Option<Interval>) {
+    match interval {
+        Some(interval) => {
+            interval.tick().await;
+        }
+        None => std::future::pending().await,
+    }
+}
+
+async fn run_client(context: Arc<Context>, index: usize, room_id: RoomId) -> (ClientStats, Option<(RoomId, PlayerId)>) {
+    let args = &context.args;
+    let mut stats = ClientStats::default();
+    let mut rng = SimpleRng::new(args.seed.wrapping_add(index as u64));
+    let mut strategy = StrategyKind::RandomWalk.build(rng.next_u64());
+
+    let join = context.client.post(context.room_url(room_id, "join"));
+    let session = match timed(&mut stats, "join", join).await {
+        Some((status, body)) if status.is_success() => serde_json::from_slice::<JoinResponse>(&body).ok(),
+        _ => None,
+    };
+
+    let jitter = Duration::from_millis(rng.below(1000) as u64);
+    let game_state: Mutex<Option<GameState>> = Mutex::new(None);
+
+    let poll_loop = async {
+        let mut stats = ClientStats::default();
+        let mut interval = rate_interval(args.poll_rate, jitter);
+        let mut restart_requested_at = None;
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep_until(context.deadline) => break,
+                _ = tick(&mut interval) => {}
+            }
+
+            let since = game_state.lock().unwrap().as_ref().map(|state| state.version);
+            let url = match since {
+                Some(version) => format!("{}?since={}", context.room_url(room_id, "state/updates"), version),
+                None => context.room_url(room_id, "state/updates"),
+            };
+            let Some((status, body)) = timed(&mut stats, "poll", context.client.get(url)).await else { continue };
+            let finished_version = {
+                let mut game_state = game_state.lock().unwrap();
+                if status.is_success()
+                    && let Ok(update) = serde_json::from_slice::<StateUpdate>(&body)
+                    && !update.apply_to(&mut game_state)
+                {
+                    *game_state = None;
+                }
+                game_state.as_ref().filter(|state| state.status == GameStatus::Finished).map(|state| state.version)
+            };
+
+            if let (Some(session), Some(version)) = (&session, finished_version)
+                && restart_requested_at != Some(version)
+            {
+                restart_requested_at = Some(version);
+                let restart = context
+                    .client
+                    .post(context.room_url(room_id, "restart"))
+                    .bearer_auth(&session.token)
+                    .json(&RestartRequest { keep_players: true });
+                timed(&mut stats, "restart", restart).await;
+            }
+        }
+        stats
+    };
+
+    let move_loop = async {
+        let mut stats = ClientStats::default();
+        let Some(session) = &session else { return stats };
+        let mut interval = rate_interval(args.move_rate, jitter);
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep_until(context.deadline) => break,
+                _ = tick(&mut interval) => {}
+            }
+
+            let direction = match game_state.lock().unwrap().as_ref() {
+                Some(state) => strategy.choose_move(state, session.player.id),
+                None => None,
+            };
+            let Some(direction) = direction else { continue };
+
+            let payload = MovePayload { player_id: session.player.id, direction };
+            let request = context
+                .client
+                .post(format!("{}/game/{}/move", context.move_url, room_id))
+                .bearer_auth(&session.token)
+                .json(&payload);
+            if let Some((status, _)) = timed(&mut stats, "move", request).await
+                && status.is_success()
+            {
+                stats.accepted_moves += 1;
+            }
+        }
+        stats
+    };
+
+    let join_loop = async {
+        let mut stats = ClientStats::default();
+        let mut interval = rate_interval(args.join_rate, jitter);
+        loop {
+            tokio::select! {
+                _ = tokio::time::sleep_until(context.deadline) => break,
+                _ = tick(&mut interval) => {
+                    timed(&mut stats, "join", context.client.post(context.room_url(room_id, "join"))).await;
+                }
+            }
+        }
+        stats
+    };
+
+    let (poll_stats, move_stats, join_stats) = tokio::join!(poll_loop, move_loop, join_loop);
+    stats.merge(poll_stats);
+    stats.merge(move_stats);
+    stats.merge(join_stats);
+
+    (stats, session.map(|session| (room_id, session.player.id)))
+}
@@ -0,0 +1,256 @@
+use game_kernel::{GameEvent, GameEventKind, PlayerId};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+// ===================================================================================
+// COLETA DE MÉTRICAS
+// ===================================================================================
+
+#[derive(Default)]
+pub struct EndpointStats {
+    latencies_us: Vec<u64>,
+    status_counts: BTreeMap<u16, usize>,
+    network_errors: usize,
+}
+
+impl EndpointStats {
+    pub fn record(&mut self, latency: Duration, status: Option<u16>) {
+        self.latencies_us.push(latency.as_micros() as u64);
+        match status {
+            Some(status) => *self.status_counts.entry(status).or_default() += 1,
+            None => self.network_errors += 1,
+        }
+    }
+
+    pub fn merge(&mut self, other: EndpointStats) {
+        self.latencies_us.extend(other.latencies_us);
+        for (status, count) in other.status_counts {
+            *self.status_counts.entry(status).or_default() += count;
+        }
+        self.network_errors += other.network_errors;
+    }
+
+    fn summarize(mut self, elapsed: Duration) -> EndpointReport {
+        self.latencies_us.sort_unstable();
+        let count = self.latencies_us.len();
+        EndpointReport {
+            count,
+            throughput_rps: count as f64 / elapsed.as_secs_f64(),
+            p50_ms: percentile_ms(&self.latencies_us, 50.0),
+            p95_ms: percentile_ms(&self.latencies_us, 95.0),
+            p99_ms: percentile_ms(&self.latencies_us, 99.0),
+            max_ms: self.latencies_us.last().map_or(0.0, |&us| us as f64 / 1000.0),
+            status_counts: self.status_counts.into_iter().map(|(status, n)| (status.to_string(), n)).collect(),
+            network_errors: self.network_errors,
+        }
+    }
+}
+
+fn percentile_ms(sorted_us: &[u64], percentile: f64) -> f64 {
+    if sorted_us.is_empty() {
+        return 0.0;
+    }
+    let rank = ((percentile / 100.0) * sorted_us.len() as f64).ceil() as usize;
+    sorted_us[rank.clamp(1, sorted_us.len()) - 1] as f64 / 1000.0
+}
+
+#[derive(Default)]
+pub struct ClientStats {
+    pub endpoints: BTreeMap<&'static str, EndpointStats>,
+    pub accepted_moves: usize,
+}
+
+impl ClientStats {
+    pub fn record(&mut self, endpoint: &'static str, latency: Duration, status: Option<u16>) {
+        self.endpoints.entry(endpoint).or_default().record(latency, status);
+    }
+
+    pub fn merge(&mut self, other: ClientStats) {
+        for (endpoint, stats) in other.endpoints {
+            self.endpoints.entry(endpoint).or_default().merge(stats);
+        }
+        self.accepted_moves += other.accepted_moves;
+    }
+}
+
+// Conta os movimentos registrados do jogador desde a sua entrada. Um reinício que esvazia
+// a sala encerra a contagem: a partir dali o mesmo id pertence a outro jogador.
+pub fn logged_moves(events: &[GameEvent], player_id: PlayerId) -> usize {
+    let mut count = None;
+    for event in events {
+        match event.kind {
+            GameEventKind::Join { player_id: id, .. } if id == player_id && count.is_none() => count = Some(0),
+            GameEventKind::Move { player_id: id, .. } if id == player_id => {
+                if let Some(count) = &mut count {
+                    *count += 1;
+                }
+            }
+            GameEventKind::Restart { keep_players: false } if count.is_some() => break,
+            _ => {}
+        }
+    }
+    count.unwrap_or(0)
+}
+
+// ===================================================================================
+// RELATÓRIO
+// ===================================================================================
+
+#[derive(Serialize)]
+pub struct EndpointReport {
+    pub count: usize,
+    pub throughput_rps: f64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+    pub status_counts: BTreeMap<String, usize>,
+    pub network_errors: usize,
+}
+
+#[derive(Serialize)]
+pub struct LostUpdates {
+    pub accepted_moves: usize,
+    pub logged_moves: usize,
+    pub lost: usize,
+    pub rate: f64,
+}
+
+#[derive(Serialize)]
+pub struct Report<C: Serialize> {
+    pub config: C,
+    pub elapsed_secs: f64,
+    pub total_requests: usize,
+    pub throughput_rps: f64,
+    pub endpoints: BTreeMap<&'static str, EndpointReport>,
+    pub lost_updates: LostUpdates,
+}
+
+impl<C: Serialize> Report<C> {
+    pub fn new(config: C, stats: ClientStats, logged_moves: usize, elapsed: Duration) -> Self {
+        let endpoints: BTreeMap<_, _> =
+            stats.endpoints.into_iter().map(|(name, stats)| (name, stats.summarize(elapsed))).collect();
+        let total_requests = endpoints.values().map(|e| e.count).sum();
+        let lost = stats.accepted_moves.saturating_sub(logged_moves);
+        let rate = if stats.accepted_moves == 0 { 0.0 } else { lost as f64 / stats.accepted_moves as f64 };
+
+        Report {
+            config,
+            elapsed_secs: elapsed.as_secs_f64(),
+            total_requests,
+            throughput_rps: total_requests as f64 / elapsed.as_secs_f64(),
+            endpoints,
+            lost_updates: LostUpdates { accepted_moves: stats.accepted_moves, logged_moves, lost, rate },
+        }
+    }
+
+    pub fn print(&self) {
+        println!();
+        println!("Duração: {:.1}s | Requisições: {} | Vazão: {:.1} req/s", self.elapsed_secs, self.total_requests, self.throughput_rps);
+        println!();
+        println!("{:<10} {:>8} {:>9} {:>9} {:>9} {:>9} {:>9}  status", "rota", "total", "req/s", "p50 ms", "p95 ms", "p99 ms", "máx ms");
+        for (name, e) in &self.endpoints {
+            let mut statuses: Vec<_> = e.status_counts.iter().map(|(status, n)| format!("{}={}", status, n)).collect();
+            if e.network_errors > 0 {
+                statuses.push(format!("rede={}", e.network_errors));
+            }
+            println!(
+                "{:<10} {:>8} {:>9.1} {:>9.2} {:>9.2} {:>9.2} {:>9.2}  {}",
+                name, e.count, e.throughput_rps, e.p50_ms, e.p95_ms, e.p99_ms, e.max_ms, statuses.join(" ")
+            );
+        }
+        println!();
+        let lost = &self.lost_updates;
+        println!(
+            "Movimentos aceitos: {} | registrados: {} | perdidos: {} ({:.2}%)",
+            lost.accepted_moves, lost.logged_moves, lost.lost, lost.rate * 100.0
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use game_kernel::{Direction, GameStatus};
+
+    fn event(kind: GameEventKind) -> GameEvent {
+        GameEvent { timestamp_ms: 0, kind }
+    }
+
+    fn join(player_id: PlayerId) -> GameEvent {
+        event(GameEventKind::Join { player_id, x: 0, y: 0 })
+    }
+
+    fn step(player_id: PlayerId) -> GameEvent {
+        event(GameEventKind::Move { player_id, direction: Direction::Right, x: 1, y: 0 })
+    }
+
+    fn client_stats(endpoint: &'static str, latencies_ms: &[u64], status: Option<u16>, accepted_moves: usize) -> ClientStats {
+        let mut stats = ClientStats { accepted_moves, ..ClientStats::default() };
+        for &ms in latencies_ms {
+            stats.record(endpoint, Duration::from_millis(ms), status);
+        }
+        stats
+    }
+
+    #[test]
+    fn merged_stats_add_up_counts_and_percentiles() {
+        let mut total = client_stats("move", &[1, 2, 3, 4], Some(200), 4);
+        total.merge(client_stats("move", &[5, 6, 7, 8, 9, 10], Some(409), 2));
+        total.merge(client_stats("move", &[20], None, 0));
+        total.merge(client_stats("poll", &[1], Some(200), 0));
+
+        let report = Report::new((), total, 6, Duration::from_secs(2));
+        assert_eq!(report.total_requests, 12);
+        assert_eq!(report.throughput_rps, 6.0);
+
+        let moves = &report.endpoints["move"];
+        assert_eq!(moves.count, 11);
+        assert_eq!((moves.p50_ms, moves.p95_ms, moves.p99_ms, moves.max_ms), (6.0, 20.0, 20.0, 20.0));
+        assert_eq!(moves.status_counts, BTreeMap::from([("200".to_string(), 4), ("409".to_string(), 6)]));
+        assert_eq!(moves.network_errors, 1);
+        assert_eq!(report.endpoints["poll"].count, 1);
+    }
+
+    #[test]
+    fn lost_updates_compare_accepted_and_logged_moves() {
+        let report = Report::new((), client_stats("move", &[], None, 8), 6, Duration::from_secs(1));
+        let lost = &report.lost_updates;
+        assert_eq!((lost.accepted_moves, lost.logged_moves, lost.lost, lost.rate), (8, 6, 2, 0.25));
+
+        let report = Report::new((), ClientStats::default(), 3, Duration::from_secs(1));
+        assert_eq!((report.lost_updates.lost, report.lost_updates.rate), (0, 0.0));
+    }
+
+    #[test]
+    fn moves_are_counted_from_the_join_and_across_rematches() {
+        let events = [
+            step(1),
+            join(1),
+            join(2),
+            step(1),
+            step(2),
+            event(GameEventKind::Status { status: GameStatus::Finished }),
+            event(GameEventKind::Restart { keep_players: true }),
+            step(1),
+        ];
+        assert_eq!(logged_moves(&events, 1), 2);
+        assert_eq!(logged_moves(&events, 2), 1);
+        assert_eq!(logged_moves(&events, 3), 0);
+    }
+
+    #[test]
+    fn moves_from_another_epoch_are_not_counted() {
+        let events = [
+            join(1),
+            step(1),
+            event(GameEventKind::Status { status: GameStatus::Finished }),
+            event(GameEventKind::Restart { keep_players: false }),
+            join(1),
+            step(1),
+            step(1),
+        ];
+        assert_eq!(logged_moves(&events, 1), 1);
+    }
+}
@@ -64,6 +64,7 @@ minikube image load servico_b:latest
 minikube image load gateway_go:latest
 
 Write-Host "Aplicando manifestos Kubernetes..." -ForegroundColor Green
+kubectl apply -f session_secret.yaml
 kubectl apply -f gateway_go_deployment.yaml
 kubectl apply -f service_a_deployment.yaml
 kubectl apply -f service_b_deployment.yaml
@@ -53,6 +53,7 @@ minikube image load servico_b:latest
 minikube image load gateway_go:latest
 
 echo "Aplicando manifestos Kubernetes..."
+kubectl apply -f session_secret.yaml
 kubectl apply -f gateway_go_deployment.yaml
 kubectl apply -f service_a_deployment.yaml
 kubectl apply -f service_b_deployment.yaml
@@ -2,8 +2,13 @@ package main
 
 import (
 	"bytes"
+	"crypto/rand"
+	"encoding/hex"
 	"io"
+	"log"
 	"net/http"
+	"net/http/httputil"
+	"net/url"
 	"os"
 
 	"github.com/gin-contrib/cors"
@@ -15,6 +20,8 @@ var (
 	serviceB_URL = getenvDefault("SERVICE_B_URL", "http://service-b:3001")
 )
 
+const requestIDHeader = "X-Request-Id"
+
 func getenvDefault(key, def string) string {
 	if v := os.Getenv(key); v != "" {
 		return v
@@ -22,6 +29,20 @@ func getenvDefault(key, def string) string {
 	return def
 }
 
+func requestID() gin.HandlerFunc {
+	return func(c *gin.Context) {
+		id := c.GetHeader(requestIDHeader)
+		if id == "" {
+			buf := make([]byte, 16)
+			rand.Read(buf)
+			id = hex.EncodeToString(buf)
+		}
+		c.Request.Header.Set(requestIDHeader, id)
+		c.Writer.Header().Set(requestIDHeader, id)
+		c.Next()
+	}
+}
+
 func proxyRequest(c *gin.Context, targetURL string) {
 	body, err := io.ReadAll(c.Request.Body)
 	if err != nil {
@@ -54,7 +75,7 @@ func proxyRequest(c *gin.Context, targetURL string) {
 	}
 
 	for key, values := range resp.Header {
-		if key == "Access-Control-Allow-Origin" || key == "Access-Control-Allow-Methods" {
+		if key == "Access-Control-Allow-Origin" || key == "Access-Control-Allow-Methods" || key == requestIDHeader {
 			continue
 		}
 		for _, value := range values {
@@ -65,22 +86,64 @@ func proxyRequest(c *gin.Context, targetURL string) {
 	c.Data(resp.StatusCode, resp.Header.Get("Content-Type"), responseBody)
 }
 
+func proxyWebSocket(targetURL string) gin.HandlerFunc {
+	target, err := url.Parse(targetURL)
+	if err != nil {
+		log.Fatalf("URL inválida para proxy WebSocket: %v", err)
+	}
+	proxy := httputil.NewSingleHostReverseProxy(target)
+	return func(c *gin.Context) {
+		proxy.ServeHTTP(c.Writer, c.Request)
+	}
+}
+
 func main() {
 	router := gin.Default()
-	router.Use(cors.Default())
+	corsConfig := cors.DefaultConfig()
+	corsConfig.AllowAllOrigins = true
+	corsConfig.AddAllowHeaders("Authorization", requestIDHeader)
+	corsConfig.AddExposeHeaders(requestIDHeader)
+	router.Use(cors.New(corsConfig))
+	router.Use(requestID())
+
+	router.GET("/game/rooms", func(c *gin.Context) {
+		proxyRequest(c, serviceB_URL)
+	})
+
+	router.POST("/game/rooms", func(c *gin.Context) {
+		proxyRequest(c, serviceB_URL)
+	})
+
+	router.POST("/game/:room_id/join", func(c *gin.Context) {
+		proxyRequest(c, serviceB_URL)
+	})
+
+	router.POST("/game/:room_id/ai", func(c *gin.Context) {
+		proxyRequest(c, serviceB_URL)
+	})
 
-	router.POST("/game/join", func(c *gin.Context) {
+	router.GET("/game/:room_id/state", func(c *gin.Context) {
 		proxyRequest(c, serviceB_URL)
 	})
 
-	router.GET("/game/state", func(c *gin.Context) {
+	router.GET("/game/:room_id/state/updates", func(c *gin.Context) {
 		proxyRequest(c, serviceB_URL)
 	})
 
-	router.POST("/game/move", func(c *gin.Context) {
+	router.POST("/game/:room_id/move", func(c *gin.Context) {
 		proxyRequest(c, serviceA_URL)
 	})
 
+	router.POST("/game/:room_id/restart", func(c *gin.Context) {
+		proxyRequest(c, serviceB_URL)
+	})
+
+	router.GET("/game/:room_id/replay", func(c *gin.Context) {
+		proxyRequest(c, serviceB_URL)
+	})
+
+	router.GET("/game/:room_id/ws", proxyWebSocket(serviceB_URL))
+
 	println("Gateway P (Go) rodando em http://127.0.0.1:8000")
 	router.Run(":8000")
 }
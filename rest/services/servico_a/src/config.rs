@@ -0,0 +1,100 @@
+use clap::Parser;
+use serde::Deserialize;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use servico_comum::telemetry::{validate_otlp_endpoint, LogFormat};
+
+const DEFAULT_LISTEN_ADDR: &str = "0.0.0.0:3002";
+const DEFAULT_SERVICE_B_URL: &str = "http://service-b:3001";
+
+// ===================================================================================
+// LINHA DE COMANDO E VARIÁVEIS DE AMBIENTE
+// ===================================================================================
+
+#[derive(Parser)]
+#[command(name = "servico_a", about = "Serviço A: validação e aplicação de movimentos")]
+pub struct Args {
+    /// Arquivo de configuração TOML
+    #[arg(long, env = "SERVICO_A_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// Endereço em que o serviço escuta
+    #[arg(long, env = "SERVICO_A_LISTEN")]
+    listen: Option<SocketAddr>,
+
+    /// URL base do Serviço B
+    #[arg(long, env = "SERVICE_B_URL")]
+    service_b_url: Option<String>,
+
+    /// Formato dos logs
+    #[arg(long, value_enum, env = "SERVICO_A_LOG_FORMAT")]
+    log_format: Option<LogFormat>,
+
+    /// Endpoint OTLP/HTTP para exportar spans (ex.: http://localhost:4318)
+    #[arg(long, env = "OTEL_EXPORTER_OTLP_ENDPOINT")]
+    otlp_endpoint: Option<String>,
+
+    /// Aceita o segredo de desenvolvimento quando SESSION_SECRET não estiver definido
+    #[arg(long, env = "ALLOW_DEV_SESSION_SECRET")]
+    dev_session_secret: bool,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct FileConfig {
+    listen: Option<SocketAddr>,
+    service_b_url: Option<String>,
+    log_format: Option<LogFormat>,
+    otlp_endpoint: Option<String>,
+    dev_session_secret: Option<bool>,
+}
+
+// ===================================================================================
+// CONFIGURAÇÃO FINAL
+// ===================================================================================
+
+pub struct Config {
+    pub listen: SocketAddr,
+    pub service_b_url: String,
+    pub log_format: LogFormat,
+    pub otlp_endpoint: Option<String>,
+    pub dev_session_secret: bool,
+}
+
+impl Args {
+    pub fn into_config(self) -> Result<Config, String> {
+        let file = match &self.config {
+            Some(path) => {
+                let contents = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+                toml::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e))?
+            }
+            None => FileConfig::default(),
+        };
+
+        let service_b_url = self.service_b_url.or(file.service_b_url).unwrap_or_else(|| DEFAULT_SERVICE_B_URL.to_string());
+        Ok(Config {
+            listen: self.listen.or(file.listen).unwrap_or_else(|| DEFAULT_LISTEN_ADDR.parse().unwrap()),
+            service_b_url: validate_upstream_url(&service_b_url)?,
+            log_format: self.log_format.or(file.log_format).unwrap_or_default(),
+            otlp_endpoint: self.otlp_endpoint.or(file.otlp_endpoint).map(validate_otlp_endpoint).transpose()?,
+            dev_session_secret: self.dev_session_secret || file.dev_session_secret.unwrap_or_default(),
+        })
+    }
+}
+
+fn validate_upstream_url(url: &str) -> Result<String, String> {
+    let invalid = |reason: &str| format!("URL do Serviço B inválida '{}': {}", url, reason);
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(invalid("use o formato http://host:porta"));
+    }
+
+    let parsed = reqwest::Url::parse(url).map_err(|e| invalid(&e.to_string()))?;
+    if parsed.host_str().is_none_or(str::is_empty) {
+        return Err(invalid("host ausente"));
+    }
+    if parsed.path() != "/" || parsed.query().is_some() {
+        return Err(invalid("informe apenas esquema, host e porta"));
+    }
+    Ok(url.trim_end_matches('/').to_string())
+}
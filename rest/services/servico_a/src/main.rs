@@ -1,119 +1,369 @@
 use axum::{
-    http::StatusCode,
+    extract::{rejection::JsonRejection, Extension, Path, State},
+    http::{header::AUTHORIZATION, HeaderMap, StatusCode},
+    middleware,
     response::{Json, IntoResponse, Response},
-    routing::post,
+    routing::{get, post},
     Router,
 };
-use std::net::SocketAddr;
+use clap::Parser;
+use std::collections::HashMap;
+use std::process::ExitCode;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::{Duration, Instant};
+use tokio::sync::watch;
 use game_kernel::*;
+use servico_comum::metrics::track_http;
+use servico_comum::telemetry::{self, Telemetry};
+use serde::Deserialize;
 use serde_json::json;
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, RequestId, SetRequestIdLayer};
+use tower_http::trace::TraceLayer;
+
+mod config;
+mod metrics;
+mod upstream;
+
+use config::Args;
+use metrics::Metrics;
+use upstream::ServiceBClient;
 //==================================================================================
 
 
-const SERVICE_B_URL: &str = "http:service-b:3001";
+const MAX_MOVE_ATTEMPTS: usize = 5;
+const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(20);
+
+struct AppState {
+    cached_games: Mutex<HashMap<RoomId, GameState>>,
+    session_secret: Vec<u8>,
+    service_b: ServiceBClient,
+    metrics: Metrics,
+    shutdown: watch::Sender<bool>,
+}
+
+type SharedState = Arc<AppState>;
+
+impl AppState {
+    fn cache(&self) -> MutexGuard<'_, HashMap<RoomId, GameState>> {
+        let started = Instant::now();
+        let cache = self.cached_games.lock().unwrap();
+        self.metrics.cache_lock_wait.observe(started.elapsed().as_secs_f64());
+        cache
+    }
+}
 
 #[tokio::main]
-async fn main() {
+async fn main() -> ExitCode {
+    let config = match Args::parse().into_config() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Configuração inválida: {}", e);
+            std::process::exit(2);
+        }
+    };
+    let telemetry = match Telemetry::init("servico_a", config.log_format, config.otlp_endpoint.as_deref()) {
+        Ok(telemetry) => telemetry,
+        Err(e) => {
+            eprintln!("Falha ao inicializar logs e tracing: {}", e);
+            std::process::exit(2);
+        }
+    };
+    let Some(session_secret) = load_session_secret(config.dev_session_secret) else {
+        telemetry.shutdown();
+        return ExitCode::from(2);
+    };
+    let service_b = ServiceBClient::new(&config.service_b_url).expect("Falha ao criar cliente HTTP do Serviço B");
+
+    let metrics = Metrics::new().expect("Falha ao registrar métricas");
+    let shared_state = Arc::new(AppState {
+        cached_games: Mutex::default(),
+        session_secret,
+        service_b,
+        metrics,
+        shutdown: watch::Sender::new(false),
+    });
 
     let app = Router::new()
-        .route("/game/move", post(handle_move));
+        .route("/game/{room_id}/move", post(handle_move))
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .route("/metrics", get(metrics::render))
+        .route_layer(middleware::from_fn_with_state(shared_state.metrics.registry.clone(), track_http))
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(TraceLayer::new_for_http().make_span_with(telemetry::request_span))
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+        .with_state(shared_state.clone());
+
+    tracing::info!(listen = %config.listen, service_b = %config.service_b_url, "Serviço A (Lógica) rodando");
+    let listener = tokio::net::TcpListener::bind(config.listen).await.unwrap();
+    let server = axum::serve(listener, app).with_graceful_shutdown(begin_shutdown(shared_state.clone()));
+    let mut shutdown = shared_state.shutdown.subscribe();
+    let served = tokio::select! {
+        result = server => result.map_err(|err| err.to_string()),
+        _ = async {
+            wait_for_shutdown(&mut shutdown).await;
+            tokio::time::sleep(SHUTDOWN_DRAIN_TIMEOUT).await;
+        } => Err(format!("requisições em andamento não terminaram em {:?}", SHUTDOWN_DRAIN_TIMEOUT)),
+    };
+
+    let exit_code = match served {
+        Ok(()) => {
+            tracing::info!("Requisições em andamento concluídas; encerrando");
+            ExitCode::SUCCESS
+        }
+        Err(err) => {
+            tracing::error!(error = %err, "Servidor encerrado com erro");
+            ExitCode::FAILURE
+        }
+    };
+    telemetry.shutdown();
+    exit_code
+}
+
+// ===================================================================================
+// ENCERRAMENTO
+// ===================================================================================
+
+async fn shutdown_signal() -> &'static str {
+    let interrupt = async {
+        tokio::signal::ctrl_c().await.expect("Falha ao instalar o tratador de SIGINT");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Falha ao instalar o tratador de SIGTERM")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
 
-    let addr: SocketAddr = "0.0.0.0:3002".parse().unwrap();
-    println!("Serviço A (Lógica) rodando em http://{}", addr);
-    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+    tokio::select! {
+        _ = interrupt => "SIGINT",
+        _ = terminate => "SIGTERM",
+    }
+}
+
+async fn wait_for_shutdown(shutdown: &mut watch::Receiver<bool>) {
+    let _ = shutdown.wait_for(|shutting_down| *shutting_down).await;
+}
+
+async fn begin_shutdown(state: SharedState) {
+    let signal = shutdown_signal().await;
+    tracing::info!(signal, "Encerrando: aguardando requisições em andamento");
+    state.shutdown.send_replace(true);
 }
 
 enum AppError {
     ServiceBError(reqwest::Error),
-    ClientError(StatusCode),
+    ServiceUnavailable,
+    ShuttingDown,
+    MoveRejected(MoveError),
+    InvalidPayload(JsonRejection),
+    RoomNotFound(RoomId),
+    Unauthorized(SessionError),
+    Conflict,
+}
+
+impl AppError {
+    fn reason(&self) -> &'static str {
+        match self {
+            AppError::ServiceBError(_) => "UPSTREAM_ERROR",
+            AppError::ServiceUnavailable => "SERVICE_UNAVAILABLE",
+            AppError::ShuttingDown => "SHUTTING_DOWN",
+            AppError::MoveRejected(err) => err.code(),
+            AppError::InvalidPayload(_) => "INVALID_PAYLOAD",
+            AppError::RoomNotFound(_) => "ROOM_NOT_FOUND",
+            AppError::Unauthorized(_) => "UNAUTHORIZED",
+            AppError::Conflict => "CONFLICT",
+        }
+    }
 }
 
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        let (status, message) = match self {
+        match self {
             AppError::ServiceBError(err) => {
-                eprintln!("Erro ao comunicar com o Serviço B: {:?}", err);
-                (StatusCode::BAD_GATEWAY, "Erro ao comunicar com um serviço interno.")
+                tracing::error!(error = %err, "Erro ao comunicar com o Serviço B");
+                let message = "Erro ao comunicar com um serviço interno.";
+                (StatusCode::BAD_GATEWAY, Json(json!({ "erro": message }))).into_response()
             }
-            AppError::ClientError(status) => (status, status.canonical_reason().unwrap_or("")),
-        };
+            AppError::ServiceUnavailable => {
+                let message = "O serviço de estado está indisponível. Tente novamente em instantes.";
+                (StatusCode::SERVICE_UNAVAILABLE, Json(json!({ "erro": message }))).into_response()
+            }
+            AppError::ShuttingDown => {
+                let message = "O serviço está sendo reiniciado.";
+                (StatusCode::SERVICE_UNAVAILABLE, Json(json!({ "erro": message }))).into_response()
+            }
+            AppError::MoveRejected(err) => {
+                let status = match err {
+                    MoveError::GameNotInProgress { .. } => StatusCode::PRECONDITION_FAILED,
+                    MoveError::UnknownPlayer { .. } => StatusCode::NOT_FOUND,
+                    MoveError::InvalidDirection | MoveError::OutOfBounds { .. } => StatusCode::BAD_REQUEST,
+                    MoveError::CellOwnedByOtherPlayer { .. } => StatusCode::FORBIDDEN,
+                };
+                (status, Json(MoveErrorBody::from(err))).into_response()
+            }
+            AppError::InvalidPayload(rejection) => {
+                (rejection.status(), Json(json!({ "erro": rejection.body_text() }))).into_response()
+            }
+            AppError::Unauthorized(err) => {
+                let status = match err {
+                    SessionError::MissingToken
+                    | SessionError::MalformedToken
+                    | SessionError::InvalidSignature
+                    | SessionError::StaleSession => StatusCode::UNAUTHORIZED,
+                    SessionError::WrongRoom | SessionError::WrongPlayer => StatusCode::FORBIDDEN,
+                };
+                (status, Json(json!({ "erro": err.to_string() }))).into_response()
+            }
+            AppError::RoomNotFound(room_id) => {
+                let message = format!("Sala {} não encontrada", room_id);
+                (StatusCode::NOT_FOUND, Json(json!({ "erro": message }))).into_response()
+            }
+            AppError::Conflict => {
+                tracing::warn!(attempts = MAX_MOVE_ATTEMPTS, "Movimento descartado após tentativas concorrentes");
+                let message = "O estado do jogo mudou durante o movimento. Tente novamente.";
+                (StatusCode::CONFLICT, Json(json!({ "erro": message }))).into_response()
+            }
+        }
+    }
+}
+
+async fn fetch_game_state(
+    service_b: &ServiceBClient,
+    request_id: &RequestId,
+    room_id: RoomId,
+    cached: Option<GameState>,
+) -> Result<GameState, AppError> {
+    let since = cached.as_ref().map(|g| g.version);
+    let mut game = cached;
 
-        (status, Json(json!({ "erro": message }))).into_response()
+    if !service_b.fetch_state_update(request_id, room_id, since).await?.apply_to(&mut game) {
+        game = None;
+        service_b.fetch_state_update(request_id, room_id, None).await?.apply_to(&mut game);
     }
+
+    Ok(game.expect("snapshot sempre substitui o estado"))
 }
 
-async fn handle_move(
-    Json(payload): Json<MovePayload>,
-) -> Result<Json<GameState>, AppError> {
-    let client = reqwest::Client::new();
+fn authorize_move(
+    state: &AppState,
+    headers: &HeaderMap,
+    room_id: RoomId,
+    player_id: PlayerId,
+) -> Result<SessionClaims, AppError> {
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or(AppError::Unauthorized(SessionError::MissingToken))?;
 
-    let game_state_url = format!("{}/game/state", SERVICE_B_URL);
+    let claims = verify_session_token(&state.session_secret, token).map_err(AppError::Unauthorized)?;
+    claims.authorize(room_id, player_id).map_err(AppError::Unauthorized)?;
+    Ok(claims)
+}
 
-    let response = client
-        .get(game_state_url)
-        .send()
-        .await
-        .map_err(AppError::ServiceBError)?;
+async fn healthz() -> &'static str {
+    "ok"
+}
 
-    if !response.status().is_success() {
-        eprintln!("Erro ao obter estado do jogo do Serviço B: Status {}", response.status());
-        return Err(AppError::ServiceBError(response.error_for_status().unwrap_err()));
+async fn readyz(State(state): State<SharedState>) -> Result<Json<serde_json::Value>, AppError> {
+    if *state.shutdown.borrow() {
+        return Err(AppError::ShuttingDown);
     }
+    state.service_b.check_ready().await?;
+    Ok(Json(json!({ "status": "ok" })))
+}
 
-    let mut game: GameState = response
-        .json()
-        .await
-        .map_err(AppError::ServiceBError)?;
+// A direção chega como texto para que um valor desconhecido seja recusado como
+// INVALID_DIRECTION, no formato das demais recusas de movimento.
+#[derive(Deserialize)]
+struct MoveRequest {
+    player_id: PlayerId,
+    direction: String,
+}
 
-    if game.status != GameStatus::InProgress {
-        return Err(StatusCode::PRECONDITION_FAILED.into());
-    }
+impl TryFrom<MoveRequest> for MovePayload {
+    type Error = MoveError;
 
-    let (next_x, next_y, player_id) = {
-        let player = match game.players.get(&payload.player_id) {
-            Some(p) => p,
-            None => return Err(StatusCode::NOT_FOUND.into()),
-        };
+    fn try_from(request: MoveRequest) -> Result<Self, MoveError> {
+        Ok(MovePayload { player_id: request.player_id, direction: request.direction.parse()? })
+    }
+}
 
-        let (mut next_x, mut next_y) = (player.x, player.y);
-        match payload.direction.as_str() {
-            "UP" => if player.y > 0 { next_y -= 1; },
-            "DOWN" => if player.y < game.height - 1 { next_y += 1; },
-            "LEFT" => if player.x > 0 { next_x -= 1; },
-            "RIGHT" => if player.x < game.width - 1 { next_x += 1; },
-            _ => return Err(StatusCode::BAD_REQUEST.into()),
+#[tracing::instrument(
+    skip_all,
+    fields(room_id = room_id, player_id = tracing::field::Empty, direction = tracing::field::Empty)
+)]
+async fn handle_move(
+    State(state): State<SharedState>,
+    Path(room_id): Path<RoomId>,
+    Extension(request_id): Extension<RequestId>,
+    headers: HeaderMap,
+    request: Result<Json<MoveRequest>, JsonRejection>,
+) -> Result<Json<GameState>, AppError> {
+    let payload = request
+        .map_err(AppError::InvalidPayload)
+        .and_then(|Json(request)| MovePayload::try_from(request).map_err(AppError::from));
+    let result = match payload {
+        Ok(payload) => {
+            let span = tracing::Span::current();
+            span.record("player_id", payload.player_id);
+            span.record("direction", tracing::field::display(payload.direction));
+            execute_move(&state, &headers, &request_id, room_id, payload).await
         }
-        (next_x, next_y, player.id)
+        Err(err) => Err(err),
     };
-
-    let destination_cell = game.grid[next_y][next_x];
-    if destination_cell != CellState::Owned(player_id) && destination_cell != CellState::Neutral {
-        return Err(StatusCode::FORBIDDEN.into());
+    match &result {
+        Ok(game) => {
+            tracing::info!(version = game.version, "Movimento aceito");
+            state.metrics.move_accepted();
+        }
+        Err(err) => {
+            tracing::info!(reason = err.reason(), "Movimento recusado");
+            state.metrics.move_rejected(err.reason());
+        }
     }
+    result.map(Json)
+}
 
-    game.grid[next_y][next_x] = CellState::Owned(player_id);
-    let player = game.players.get_mut(&payload.player_id).unwrap();
-    player.x = next_x;
-    player.y = next_y;
+async fn execute_move(
+    state: &AppState,
+    headers: &HeaderMap,
+    request_id: &RequestId,
+    room_id: RoomId,
+    payload: MovePayload,
+) -> Result<GameState, AppError> {
+    let claims = authorize_move(state, headers, room_id, payload.player_id)?;
 
-    let has_neutral_cells = game.grid.iter().any(|row| row.iter().any(|&cell| cell == CellState::Neutral));
+    for _ in 0..MAX_MOVE_ATTEMPTS {
+        let cached = state.cache().get(&room_id).cloned();
+        let base = fetch_game_state(&state.service_b, request_id, room_id, cached).await?;
+        state.cache().insert(room_id, base.clone());
+        claims.check_epoch(&base).map_err(AppError::Unauthorized)?;
 
-    if !has_neutral_cells {
-        game.status = GameStatus::Finished;
-    }
+        let mut game = base.clone();
+        game.apply_move(payload.player_id, payload.direction)?;
+        game.version = base.version + 1;
+        let delta = base.diff(&game).expect("um movimento não altera as dimensões do mapa");
+
+        let Some(accepted) = state.service_b.post_delta(request_id, room_id, &delta).await? else {
+            continue;
+        };
 
-    client.post(format!("{}/game/state/update", SERVICE_B_URL))
-        .json(&game)
-        .send()
-        .await
-        .map_err(AppError::ServiceBError)?;
+        game.version = accepted.version;
+        state.cache().insert(room_id, game.clone());
+        return Ok(game);
+    }
 
-    Ok(Json(game))
+    Err(AppError::Conflict)
 }
 
-impl From<StatusCode> for AppError {
-    fn from(sc: StatusCode) -> Self {
-        AppError::ClientError(sc)
+impl From<MoveError> for AppError {
+    fn from(err: MoveError) -> Self {
+        AppError::MoveRejected(err)
     }
 }
\ No newline at end of file
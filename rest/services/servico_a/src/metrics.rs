@@ -0,0 +1,44 @@
+use axum::{extract::State, response::Response};
+use prometheus::{Histogram, IntCounter, IntCounterVec, IntGauge, Opts};
+use servico_comum::metrics::MetricsRegistry;
+
+use crate::SharedState;
+
+pub struct Metrics {
+    pub registry: MetricsRegistry,
+    moves_accepted: IntCounter,
+    moves_rejected: IntCounterVec,
+    pub cached_games: IntGauge,
+    pub cache_lock_wait: Histogram,
+}
+
+impl Metrics {
+    pub fn new() -> prometheus::Result<Self> {
+        let registry = MetricsRegistry::new("servico_a")?;
+        let moves_accepted = registry.register(IntCounter::new("moves_accepted_total", "Movimentos aceitos")?)?;
+        let moves_rejected =
+            registry.register(IntCounterVec::new(Opts::new("moves_rejected_total", "Movimentos recusados"), &["reason"])?)?;
+        let cached_games = registry.register(IntGauge::new("cached_games", "Partidas com estado em cache")?)?;
+        let cache_lock_wait =
+            registry.lock_wait_histogram("cache_lock_wait_seconds", "Tempo de espera pelo mutex do cache de partidas")?;
+
+        Ok(Metrics { registry, moves_accepted, moves_rejected, cached_games, cache_lock_wait })
+    }
+
+    pub fn move_accepted(&self) {
+        self.moves_accepted.inc();
+    }
+
+    pub fn move_rejected(&self, reason: &str) {
+        self.moves_rejected.with_label_values(&[reason]).inc();
+    }
+}
+
+// ===================================================================================
+// EXPOSIÇÃO
+// ===================================================================================
+
+pub async fn render(State(state): State<SharedState>) -> Response {
+    state.metrics.cached_games.set(state.cache().len() as i64);
+    state.metrics.registry.render()
+}
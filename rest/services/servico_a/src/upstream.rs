@@ -0,0 +1,216 @@
+use game_kernel::*;
+use reqwest::{Client, RequestBuilder, Response, StatusCode};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tower_http::request_id::RequestId;
+
+use servico_comum::telemetry::trace_headers;
+use crate::AppError;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(500);
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+const POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+const READY_TIMEOUT: Duration = Duration::from_secs(1);
+const GET_MAX_ATTEMPTS: u32 = 3;
+const GET_INITIAL_BACKOFF: Duration = Duration::from_millis(50);
+const BREAKER_FAILURE_THRESHOLD: u32 = 5;
+const BREAKER_OPEN_DURATION: Duration = Duration::from_secs(5);
+
+// ===================================================================================
+// CIRCUIT BREAKER
+// ===================================================================================
+
+enum BreakerState {
+    Closed { failures: u32 },
+    Open { until: Instant },
+    // Uma requisição de teste está em andamento; as demais são recusadas até `until`.
+    HalfOpen { until: Instant },
+}
+
+pub struct CircuitBreaker {
+    state: Mutex<BreakerState>,
+    failure_threshold: u32,
+    open_duration: Duration,
+}
+
+impl CircuitBreaker {
+    pub fn new(failure_threshold: u32, open_duration: Duration) -> Self {
+        CircuitBreaker { state: Mutex::new(BreakerState::Closed { failures: 0 }), failure_threshold, open_duration }
+    }
+
+    fn try_acquire(&self) -> bool {
+        let mut state = self.state.lock().unwrap();
+        let now = Instant::now();
+        match *state {
+            BreakerState::Closed { .. } => true,
+            BreakerState::Open { until } | BreakerState::HalfOpen { until } if now < until => false,
+            BreakerState::Open { .. } | BreakerState::HalfOpen { .. } => {
+                *state = BreakerState::HalfOpen { until: now + self.open_duration };
+                true
+            }
+        }
+    }
+
+    fn is_open(&self) -> bool {
+        matches!(*self.state.lock().unwrap(), BreakerState::Open { until } if Instant::now() < until)
+    }
+
+    fn record_success(&self) {
+        let mut state = self.state.lock().unwrap();
+        if !matches!(*state, BreakerState::Closed { .. }) {
+            tracing::info!("Serviço B respondeu novamente; circuito fechado");
+        }
+        *state = BreakerState::Closed { failures: 0 };
+    }
+
+    fn record_failure(&self) {
+        let mut state = self.state.lock().unwrap();
+        let failures = match *state {
+            BreakerState::Closed { failures } => failures + 1,
+            BreakerState::Open { .. } | BreakerState::HalfOpen { .. } => self.failure_threshold,
+        };
+
+        *state = if failures >= self.failure_threshold {
+            tracing::warn!(open_for = ?self.open_duration, "Serviço B indisponível; circuito aberto");
+            BreakerState::Open { until: Instant::now() + self.open_duration }
+        } else {
+            BreakerState::Closed { failures }
+        };
+    }
+}
+
+// ===================================================================================
+// CLIENTE DO SERVIÇO B
+// ===================================================================================
+
+pub struct ServiceBClient {
+    http: Client,
+    base_url: String,
+    breaker: CircuitBreaker,
+}
+
+impl ServiceBClient {
+    pub fn new(base_url: &str) -> reqwest::Result<Self> {
+        let http = Client::builder()
+            .connect_timeout(CONNECT_TIMEOUT)
+            .timeout(REQUEST_TIMEOUT)
+            .pool_idle_timeout(POOL_IDLE_TIMEOUT)
+            .build()?;
+        let breaker = CircuitBreaker::new(BREAKER_FAILURE_THRESHOLD, BREAKER_OPEN_DURATION);
+        Ok(ServiceBClient { http, base_url: base_url.trim_end_matches('/').to_string(), breaker })
+    }
+
+    fn room_url(&self, room_id: RoomId, path: &str) -> String {
+        format!("{}/game/{}/{}", self.base_url, room_id, path)
+    }
+
+    async fn send(&self, request: RequestBuilder, request_id: &RequestId) -> Result<Response, AppError> {
+        if !self.breaker.try_acquire() {
+            return Err(AppError::ServiceUnavailable);
+        }
+
+        match request.headers(trace_headers(request_id)).send().await {
+            Ok(response) if response.status().is_server_error() => {
+                self.breaker.record_failure();
+                Ok(response)
+            }
+            Ok(response) => {
+                self.breaker.record_success();
+                Ok(response)
+            }
+            Err(err) => {
+                self.breaker.record_failure();
+                if err.is_connect() || err.is_timeout() {
+                    tracing::warn!(error = %err, "Serviço B inacessível");
+                    Err(AppError::ServiceUnavailable)
+                } else {
+                    Err(AppError::ServiceBError(err))
+                }
+            }
+        }
+    }
+
+    async fn get_with_retry(&self, url: &str, request_id: &RequestId) -> Result<Response, AppError> {
+        let mut backoff = GET_INITIAL_BACKOFF;
+        for attempt in 1..=GET_MAX_ATTEMPTS {
+            let result = self.send(self.http.get(url), request_id).await;
+            let retryable = match &result {
+                Ok(response) => response.status().is_server_error(),
+                Err(AppError::ServiceUnavailable) => !self.breaker.is_open(),
+                Err(_) => false,
+            };
+            if !retryable || attempt == GET_MAX_ATTEMPTS {
+                return result;
+            }
+            tracing::debug!(attempt, ?backoff, "Repetindo leitura do Serviço B");
+            tokio::time::sleep(backoff).await;
+            backoff *= 2;
+        }
+        unreachable!("o laço sempre retorna na última tentativa")
+    }
+
+    #[tracing::instrument(skip_all)]
+    pub async fn check_ready(&self) -> Result<(), AppError> {
+        if self.breaker.is_open() {
+            return Err(AppError::ServiceUnavailable);
+        }
+
+        let url = format!("{}/healthz", self.base_url);
+        match self.http.get(url).timeout(READY_TIMEOUT).send().await {
+            Ok(response) if response.status().is_success() => Ok(()),
+            Ok(response) => {
+                tracing::warn!(status = %response.status(), "Serviço B não está pronto");
+                Err(AppError::ServiceUnavailable)
+            }
+            Err(err) => {
+                tracing::warn!(error = %err, "Serviço B inacessível");
+                Err(AppError::ServiceUnavailable)
+            }
+        }
+    }
+
+    #[tracing::instrument(skip(self, request_id))]
+    pub async fn fetch_state_update(
+        &self,
+        request_id: &RequestId,
+        room_id: RoomId,
+        since: Option<u64>,
+    ) -> Result<StateUpdate, AppError> {
+        let url = match since {
+            Some(version) => format!("{}?since={}", self.room_url(room_id, "state/updates"), version),
+            None => self.room_url(room_id, "state/updates"),
+        };
+
+        let response = self.get_with_retry(&url, request_id).await?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Err(AppError::RoomNotFound(room_id));
+        }
+        if !response.status().is_success() {
+            tracing::error!(status = %response.status(), "Erro ao obter estado do jogo do Serviço B");
+            return Err(AppError::ServiceBError(response.error_for_status().unwrap_err()));
+        }
+        response.json().await.map_err(AppError::ServiceBError)
+    }
+
+    #[tracing::instrument(skip(self, request_id, delta), fields(from_version = delta.from_version))]
+    pub async fn post_delta(
+        &self,
+        request_id: &RequestId,
+        room_id: RoomId,
+        delta: &StateDelta,
+    ) -> Result<Option<UpdateAccepted>, AppError> {
+        let request = self.http.post(self.room_url(room_id, "state/update")).json(delta);
+        let response = self.send(request, request_id).await?;
+        if response.status() == StatusCode::CONFLICT {
+            tracing::debug!("Versão desatualizada; o movimento será recalculado");
+            return Ok(None);
+        }
+        let accepted = response
+            .error_for_status()
+            .map_err(AppError::ServiceBError)?
+            .json()
+            .await
+            .map_err(AppError::ServiceBError)?;
+        Ok(Some(accepted))
+    }
+}
@@ -0,0 +1,128 @@
+use game_kernel::*;
+use serde::{Deserialize, Serialize};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::SharedState;
+
+pub const AI_TICK_INTERVAL: Duration = Duration::from_millis(400);
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug)]
+pub struct AiSeat {
+    pub player_id: PlayerId,
+    pub difficulty: Difficulty,
+    pub seed: u64,
+}
+
+#[derive(Clone)]
+pub struct AiPlayer {
+    pub seat: AiSeat,
+    strategy: Arc<Mutex<Box<dyn Strategy + Send>>>,
+}
+
+impl AiPlayer {
+    pub fn new(seat: AiSeat) -> Self {
+        AiPlayer { seat, strategy: Arc::new(Mutex::new(seat.difficulty.build(seat.seed))) }
+    }
+}
+
+// Cada IA joga sobre a cópia já atualizada pelas jogadas das anteriores, na ordem em que
+// entraram; a sala real não é tocada aqui.
+fn plan_moves(game: &GameState, ai_players: &[AiPlayer]) -> Vec<(PlayerId, Direction)> {
+    let mut planned = game.clone();
+    let mut moves = Vec::new();
+    for ai in ai_players {
+        let player_id = ai.seat.player_id;
+        let Some(direction) = ai.strategy.lock().unwrap().choose_move(&planned, player_id) else {
+            continue;
+        };
+        if planned.apply_move(player_id, direction).is_ok() {
+            planned.check_game_end();
+            moves.push((player_id, direction));
+        }
+    }
+    moves
+}
+
+// ===================================================================================
+// TICK DOS JOGADORES CONTROLADOS PELO SERVIDOR
+// ===================================================================================
+
+pub async fn run_ai_ticks(state: SharedState) {
+    let mut interval = tokio::time::interval(AI_TICK_INTERVAL);
+    let mut shutdown = state.shutdown.subscribe();
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = crate::wait_for_shutdown(&mut shutdown) => return,
+        }
+        let rooms: Vec<_> = state.rooms.read().unwrap().values().cloned().collect();
+
+        for room in rooms {
+            let _span = tracing::debug_span!("ai_tick", room_id = room.id).entered();
+            let (snapshot, ai_players) = {
+                let store = room.lock();
+                if store.game.status != GameStatus::InProgress || store.ai_players.is_empty() {
+                    continue;
+                }
+                (store.game.clone(), store.ai_players.clone())
+            };
+
+            // As estratégias rodam fora do lock da sala; como no POST /state/update, as jogadas
+            // só valem se a sala ainda estiver na versão lida.
+            let moves = plan_moves(&snapshot, &ai_players);
+            if moves.is_empty() {
+                continue;
+            }
+
+            let mut guard = room.lock();
+            let store = &mut *guard;
+            if (store.game.version, store.game.epoch) != (snapshot.version, snapshot.epoch) {
+                state.metrics.move_rejected("ia", "VERSION_CONFLICT");
+                tracing::debug!(current_version = store.game.version, "Jogadas da IA descartadas por versão desatualizada");
+                continue;
+            }
+            for (player_id, direction) in moves {
+                let previous = store.game.clone();
+                match store.game.apply_move(player_id, direction) {
+                    Ok(_) => {
+                        room.commit(store, &previous);
+                        state.metrics.move_accepted("ia");
+                    }
+                    Err(err) => state.metrics.move_rejected("ia", err.code()),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ai_players_only_make_legal_moves() {
+        for (seed, difficulty) in (0..).zip(Difficulty::ALL.into_iter().cycle().take(12)) {
+            let mut game = create_initial_state(6, 5, 2, 3);
+            let ai_players: Vec<_> = (0..3)
+                .map(|offset| {
+                    let player = game.join_player().unwrap();
+                    AiPlayer::new(AiSeat { player_id: player.id, difficulty, seed: seed + offset })
+                })
+                .collect();
+
+            while game.status == GameStatus::InProgress {
+                let moves = plan_moves(&game, &ai_players);
+                if moves.is_empty() {
+                    break;
+                }
+                for (player_id, direction) in moves {
+                    game.resolve_move(player_id, direction)
+                        .unwrap_or_else(|err| panic!("IA {} ({}) jogou {}: {}", player_id, difficulty, direction, err));
+                    game.apply_move(player_id, direction).unwrap();
+                    game.check_game_end();
+                }
+            }
+        }
+    }
+}
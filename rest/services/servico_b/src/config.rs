@@ -0,0 +1,139 @@
+use clap::Parser;
+use game_kernel::*;
+use serde::Deserialize;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use servico_comum::telemetry::{validate_otlp_endpoint, LogFormat};
+
+const DEFAULT_LISTEN_ADDR: &str = "0.0.0.0:3001";
+const DEFAULT_GRPC_LISTEN_ADDR: &str = "0.0.0.0:50051";
+
+// ===================================================================================
+// LINHA DE COMANDO E VARIÁVEIS DE AMBIENTE
+// ===================================================================================
+
+#[derive(Parser)]
+#[command(name = "servico_b", about = "Serviço B: estado das salas e partidas")]
+pub struct Args {
+    /// Arquivo de configuração TOML
+    #[arg(long, env = "SERVICO_B_CONFIG")]
+    config: Option<PathBuf>,
+
+    /// Endereço em que o serviço escuta
+    #[arg(long, env = "SERVICO_B_LISTEN")]
+    listen: Option<SocketAddr>,
+
+    /// Endereço em que o GameStateService (gRPC) escuta
+    #[arg(long, env = "SERVICO_B_GRPC_LISTEN")]
+    grpc_listen: Option<SocketAddr>,
+
+    /// Diretório onde o estado das salas é persistido (sem ele, tudo fica em memória)
+    #[arg(long, env = "SERVICO_B_DATA_DIR")]
+    data_dir: Option<PathBuf>,
+
+    /// Largura do mapa da sala padrão
+    #[arg(long, env = "SERVICO_B_BOARD_WIDTH")]
+    board_width: Option<usize>,
+
+    /// Altura do mapa da sala padrão
+    #[arg(long, env = "SERVICO_B_BOARD_HEIGHT")]
+    board_height: Option<usize>,
+
+    /// Jogadores necessários para iniciar uma partida na sala padrão
+    #[arg(long, env = "SERVICO_B_MIN_PLAYERS")]
+    min_players: Option<usize>,
+
+    /// Máximo de jogadores por sala (e capacidade da sala padrão)
+    #[arg(long, env = "SERVICO_B_MAX_PLAYERS")]
+    max_players: Option<usize>,
+
+    /// Cores dos jogadores no formato #RRGGBB, separadas por vírgula
+    #[arg(long, env = "SERVICO_B_PALETTE", value_delimiter = ',')]
+    palette: Option<Vec<String>>,
+
+    /// Formato dos logs
+    #[arg(long, value_enum, env = "SERVICO_B_LOG_FORMAT")]
+    log_format: Option<LogFormat>,
+
+    /// Endpoint OTLP/HTTP para exportar spans (ex.: http://localhost:4318)
+    #[arg(long, env = "OTEL_EXPORTER_OTLP_ENDPOINT")]
+    otlp_endpoint: Option<String>,
+
+    /// Aceita o segredo de desenvolvimento quando SESSION_SECRET não estiver definido
+    #[arg(long, env = "ALLOW_DEV_SESSION_SECRET")]
+    dev_session_secret: bool,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(default, deny_unknown_fields)]
+struct FileConfig {
+    listen: Option<SocketAddr>,
+    grpc_listen: Option<SocketAddr>,
+    data_dir: Option<PathBuf>,
+    board_width: Option<usize>,
+    board_height: Option<usize>,
+    min_players: Option<usize>,
+    max_players: Option<usize>,
+    palette: Option<Vec<String>>,
+    log_format: Option<LogFormat>,
+    otlp_endpoint: Option<String>,
+    dev_session_secret: Option<bool>,
+}
+
+// ===================================================================================
+// CONFIGURAÇÃO FINAL
+// ===================================================================================
+
+pub struct Config {
+    pub listen: SocketAddr,
+    pub grpc_listen: SocketAddr,
+    pub data_dir: Option<PathBuf>,
+    pub limits: RoomLimits,
+    pub default_room: RoomConfig,
+    pub log_format: LogFormat,
+    pub otlp_endpoint: Option<String>,
+    pub dev_session_secret: bool,
+}
+
+impl Args {
+    pub fn into_config(self) -> Result<Config, String> {
+        let file = match &self.config {
+            Some(path) => {
+                let contents = std::fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+                toml::from_str(&contents).map_err(|e| format!("{}: {}", path.display(), e))?
+            }
+            None => FileConfig::default(),
+        };
+
+        let defaults = RoomLimits::default();
+        let limits = RoomLimits {
+            min_players: self.min_players.or(file.min_players).unwrap_or(defaults.min_players),
+            max_players: self.max_players.or(file.max_players).unwrap_or(defaults.max_players),
+        };
+        limits.validate().map_err(|e| e.to_string())?;
+
+        let default_room = RoomConfig {
+            width: self.board_width.or(file.board_width).unwrap_or(DEFAULT_BOARD_SIZE),
+            height: self.board_height.or(file.board_height).unwrap_or(DEFAULT_BOARD_SIZE),
+            min_players: limits.min_players,
+            max_players: limits.max_players,
+            palette: self.palette.or(file.palette).unwrap_or_default(),
+        };
+        default_room.validate_within(&limits).map_err(|e| e.to_string())?;
+
+        Ok(Config {
+            listen: self.listen.or(file.listen).unwrap_or_else(|| DEFAULT_LISTEN_ADDR.parse().unwrap()),
+            grpc_listen: self
+                .grpc_listen
+                .or(file.grpc_listen)
+                .unwrap_or_else(|| DEFAULT_GRPC_LISTEN_ADDR.parse().unwrap()),
+            data_dir: self.data_dir.or(file.data_dir),
+            limits,
+            default_room,
+            log_format: self.log_format.or(file.log_format).unwrap_or_default(),
+            otlp_endpoint: self.otlp_endpoint.or(file.otlp_endpoint).map(validate_otlp_endpoint).transpose()?,
+            dev_session_secret: self.dev_session_secret || file.dev_session_secret.unwrap_or_default(),
+        })
+    }
+}
@@ -0,0 +1,176 @@
+use game_kernel::grpc::gamestate::{
+    self,
+    game_state_service_server::{GameStateService, GameStateServiceServer},
+};
+use game_kernel::*;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use tonic::metadata::MetadataMap;
+use tonic::{Request, Response, Status};
+
+use crate::{verify_room_session, wait_for_shutdown, Room, SharedState};
+
+// ===================================================================================
+// GameStateService (gRPC) SOBRE UMA SALA PRÓPRIA
+// ===================================================================================
+
+// O REST numera as salas a partir de 1; a sala 0 só existe para o gRPC e fica fora das rotas REST.
+pub const GRPC_ROOM_ID: RoomId = 0;
+
+struct GrpcGameState {
+    state: SharedState,
+}
+
+impl GrpcGameState {
+    fn room(&self) -> Result<Arc<Room>, Status> {
+        self.state
+            .rooms
+            .read()
+            .unwrap()
+            .get(&GRPC_ROOM_ID)
+            .cloned()
+            .ok_or_else(|| Status::not_found("Sala do gRPC não encontrada"))
+    }
+
+    fn authorize(&self, metadata: &MetadataMap, game: &GameState) -> Result<SessionClaims, Status> {
+        verify_room_session(&self.state, metadata.as_ref(), GRPC_ROOM_ID, game)
+            .map_err(|err| Status::unauthenticated(err.to_string()))
+    }
+}
+
+fn join_status(err: JoinError) -> Status {
+    match err {
+        JoinError::GameAlreadyStarted => Status::failed_precondition(err.to_string()),
+        JoinError::GameFull => Status::resource_exhausted(err.to_string()),
+    }
+}
+
+#[tonic::async_trait]
+impl GameStateService for GrpcGameState {
+    #[tracing::instrument(skip_all, fields(room_id = GRPC_ROOM_ID))]
+    async fn join_game(
+        &self,
+        _request: Request<gamestate::JoinGameRequest>,
+    ) -> Result<Response<gamestate::JoinGameResponse>, Status> {
+        let room = self.room()?;
+        let mut store = room.lock();
+        let previous = store.game.clone();
+
+        let player = store.game.join_player().map_err(join_status)?;
+        room.commit(&mut store, &previous);
+        let claims = SessionClaims { room_id: GRPC_ROOM_ID, player_id: player.id, epoch: store.game.epoch };
+        let token = issue_session_token(&self.state.session_secret, claims, rand::random());
+        tracing::info!(player_id = player.id, "Jogador entrou via gRPC");
+        Ok(Response::new(gamestate::JoinGameResponse {
+            player: Some((&player).into()),
+            error: String::new(),
+            token,
+        }))
+    }
+
+    #[tracing::instrument(level = "debug", skip_all, fields(room_id = GRPC_ROOM_ID))]
+    async fn get_game_state(
+        &self,
+        _request: Request<gamestate::GetGameStateRequest>,
+    ) -> Result<Response<gamestate::GameStateResponse>, Status> {
+        let room = self.room()?;
+        let store = room.lock();
+        Ok(Response::new(gamestate::GameStateResponse { state: Some((&store.game).into()) }))
+    }
+
+    // O GameMoveService envia o estado completo, lido na `version` informada, com o token de
+    // sessão do jogador que moveu; um estado desatualizado é recusado em vez de sobrescrever a sala.
+    #[tracing::instrument(skip_all, fields(room_id = GRPC_ROOM_ID))]
+    async fn update_game_state(
+        &self,
+        request: Request<gamestate::UpdateGameStateRequest>,
+    ) -> Result<Response<gamestate::UpdateGameStateResponse>, Status> {
+        let (metadata, _, request) = request.into_parts();
+        let wire = request
+            .state
+            .ok_or_else(|| Status::invalid_argument(WireError::MissingField { field: "state" }.to_string()))?;
+        // Sem a versão lida não há como detectar uma escrita concorrente.
+        if wire.version.is_none() {
+            return Err(Status::invalid_argument(
+                WireError::MissingField { field: "GameState.version" }.to_string(),
+            ));
+        }
+        let incoming = GameState::try_from(wire).map_err(|err| Status::invalid_argument(err.to_string()))?;
+
+        let room = self.room()?;
+        let mut store = room.lock();
+        self.authorize(&metadata, &store.game)?;
+        if (incoming.version, incoming.epoch) != (store.game.version, store.game.epoch) {
+            self.state.metrics.move_rejected("grpc", "VERSION_CONFLICT");
+            tracing::debug!(
+                expected_version = incoming.version,
+                current_version = store.game.version,
+                "Estado recusado por versão desatualizada"
+            );
+            return Err(Status::aborted(format!(
+                "Estado desatualizado: lido na versão {}, a sala está na versão {}",
+                incoming.version, store.game.version
+            )));
+        }
+        if (incoming.width, incoming.height) != (store.game.width, store.game.height) {
+            return Err(Status::invalid_argument(format!(
+                "O mapa enviado ({}x{}) não tem as dimensões da sala ({}x{})",
+                incoming.width, incoming.height, store.game.width, store.game.height
+            )));
+        }
+        if (incoming.min_players, incoming.max_players) != (store.game.min_players, store.game.max_players) {
+            return Err(Status::invalid_argument(format!(
+                "A sala aceita de {} a {} jogadores; o estado enviado informa de {} a {}",
+                store.game.min_players, store.game.max_players, incoming.min_players, incoming.max_players
+            )));
+        }
+
+        let previous = store.game.clone();
+        store.game.status = incoming.status;
+        store.game.grid = incoming.grid;
+        store.game.players = incoming.players;
+        room.commit(&mut store, &previous);
+        self.state.metrics.move_accepted("grpc");
+        tracing::debug!(version = store.game.version, "Estado substituído via gRPC");
+        Ok(Response::new(gamestate::UpdateGameStateResponse { success: true }))
+    }
+
+    // Mesma regra do POST /restart: só uma partida encerrada pode ser reiniciada, e a sala
+    // é esvaziada a menos que `keep_players` peça uma revanche.
+    #[tracing::instrument(skip_all, fields(room_id = GRPC_ROOM_ID))]
+    async fn restart_game(
+        &self,
+        request: Request<gamestate::RestartGameRequest>,
+    ) -> Result<Response<gamestate::RestartGameResponse>, Status> {
+        let (metadata, _, request) = request.into_parts();
+        let keep_players = request.keep_players;
+        let room = self.room()?;
+        let mut store = room.lock();
+        let claims = self.authorize(&metadata, &store.game)?;
+        let previous = store.game.clone();
+
+        store
+            .game
+            .restart(keep_players)
+            .map_err(|err| Status::failed_precondition(err.to_string()))?;
+        if !keep_players {
+            store.ai_players.clear();
+        }
+        room.commit(&mut store, &previous);
+
+        tracing::info!(player_id = claims.player_id, keep_players, "Sala reiniciada via gRPC");
+        Ok(Response::new(gamestate::RestartGameResponse {}))
+    }
+}
+
+pub async fn serve(state: SharedState, listen: SocketAddr) -> Result<(), String> {
+    let mut shutdown = state.shutdown.subscribe();
+    let service = GameStateServiceServer::new(GrpcGameState { state });
+
+    tracing::info!(listen = %listen, room_id = GRPC_ROOM_ID, "GameStateService (gRPC) rodando");
+    tonic::transport::Server::builder()
+        .add_service(service)
+        .serve_with_shutdown(listen, async move { wait_for_shutdown(&mut shutdown).await })
+        .await
+        .map_err(|err| err.to_string())
+}
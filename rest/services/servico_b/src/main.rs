@@ -1,93 +1,609 @@
 use axum::{
-    extract::State,
-    http::StatusCode,
-    response::Json,
-    routing::{get, post},
+    extract::{
+        ws::{close_code, CloseFrame, Message, WebSocket, WebSocketUpgrade},
+        Path, Query, State,
+    },
+    http::{header::AUTHORIZATION, HeaderMap, StatusCode},
+    middleware,
+    response::{Json, Response},
+    routing::{any, get, post},
     Router,
 };
+use clap::Parser;
+use serde::Deserialize;
+use serde_json::{json, Value};
 use std::collections::HashMap;
-use std::net::SocketAddr;
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, RwLock};
+use std::process::ExitCode;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use prometheus::Histogram;
+use tokio::sync::watch;
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
+use tower_http::trace::TraceLayer;
 use game_kernel::*;
+use servico_comum::metrics::track_http;
+use servico_comum::telemetry::{self, Telemetry};
+
+mod ai;
+mod config;
+mod grpc_server;
+mod metrics;
+mod storage;
+
+use ai::{AiPlayer, AiSeat};
+use config::{Args, Config};
+use grpc_server::GRPC_ROOM_ID;
+use metrics::Metrics;
+use storage::{FileStorage, MemoryStorage, SavedRoom, StateStorage, StorageWriter};
+
+const SHUTDOWN_DRAIN_TIMEOUT: Duration = Duration::from_secs(20);
+
+struct GameStore {
+    game: GameState,
+    history: DeltaHistory,
+    events: Vec<GameEvent>,
+    ai_players: Vec<AiPlayer>,
+}
+
+impl GameStore {
+    fn ai_seats(&self) -> Vec<AiSeat> {
+        self.ai_players.iter().map(|ai| ai.seat).collect()
+    }
+}
+
+struct Room {
+    id: RoomId,
+    store: Mutex<GameStore>,
+    updates: watch::Sender<u64>,
+    storage: StorageWriter,
+    lock_wait: Histogram,
+}
+
+impl Room {
+    fn new(id: RoomId, saved: SavedRoom, storage: StorageWriter, lock_wait: Histogram) -> Self {
+        let (updates, _) = watch::channel(saved.game.version);
+        let store = GameStore {
+            game: saved.game,
+            history: DeltaHistory::default(),
+            events: saved.events,
+            ai_players: saved.ai_seats.into_iter().map(AiPlayer::new).collect(),
+        };
+        Room { id, store: Mutex::new(store), updates, storage, lock_wait }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, GameStore> {
+        let started = Instant::now();
+        let store = self.store.lock().unwrap();
+        self.lock_wait.observe(started.elapsed().as_secs_f64());
+        store
+    }
+
+    fn commit(&self, store: &mut GameStore, previous: &GameState) {
+        store.history.record_transition(previous, &mut store.game);
+
+        let timestamp_ms = now_millis();
+        let events: Vec<_> = transition_events(previous, &store.game)
+            .into_iter()
+            .map(|kind| GameEvent { timestamp_ms, kind })
+            .collect();
+        self.storage.append_events(self.id, events.clone());
+        store.events.extend(events);
+
+        self.persist(store);
+        self.updates.send_replace(store.game.version);
+    }
+
+    fn persist(&self, store: &GameStore) {
+        self.storage.save_room(self.id, store.game.clone(), store.ai_seats());
+    }
+}
+
+struct AppState {
+    rooms: RwLock<HashMap<RoomId, Arc<Room>>>,
+    next_room_id: AtomicU32,
+    session_secret: Vec<u8>,
+    storage: StorageWriter,
+    limits: RoomLimits,
+    palette: Vec<String>,
+    metrics: Metrics,
+    shutdown: watch::Sender<bool>,
+}
+
+type SharedState = Arc<AppState>;
+
+impl AppState {
+    fn new(
+        session_secret: Vec<u8>,
+        storage: StorageWriter,
+        limits: RoomLimits,
+        palette: Vec<String>,
+        metrics: Metrics,
+    ) -> Self {
+        AppState {
+            rooms: RwLock::default(),
+            next_room_id: AtomicU32::new(0),
+            session_secret,
+            storage,
+            limits,
+            palette,
+            metrics,
+            shutdown: watch::Sender::new(false),
+        }
+    }
+
+    fn create_room(&self, config: &RoomConfig) -> RoomId {
+        let room_id = self.next_room_id.fetch_add(1, Ordering::Relaxed) + 1;
+        self.insert_room(room_id, config);
+        room_id
+    }
+
+    fn insert_room(&self, room_id: RoomId, config: &RoomConfig) {
+        let saved = SavedRoom { id: room_id, game: config.initial_state(), ai_seats: Vec::new(), events: Vec::new() };
+        let room = Room::new(room_id, saved, self.storage.clone(), self.metrics.room_lock_wait.clone());
+        room.persist(&room.lock());
+        self.rooms.write().unwrap().insert(room_id, Arc::new(room));
+    }
+
+    fn restore_rooms(&self) -> std::io::Result<usize> {
+        let saved_rooms = self.storage.load_rooms()?;
+        let mut rooms = self.rooms.write().unwrap();
+        let restored = saved_rooms.len();
+        for saved in saved_rooms {
+            let id = saved.id;
+            let room = Room::new(id, saved, self.storage.clone(), self.metrics.room_lock_wait.clone());
+            rooms.insert(id, Arc::new(room));
+            self.next_room_id.fetch_max(id, Ordering::Relaxed);
+        }
+        Ok(restored)
+    }
+
+    fn flush(&self) -> std::io::Result<usize> {
+        let rooms = self.rooms.read().unwrap();
+        for room in rooms.values() {
+            room.persist(&room.lock());
+        }
+        self.storage.flush()?;
+        Ok(rooms.len())
+    }
+
+    fn has_room(&self, matches: impl Fn(RoomId) -> bool) -> bool {
+        self.rooms.read().unwrap().keys().any(|&id| matches(id))
+    }
+
+    fn room(&self, room_id: RoomId) -> Result<Arc<Room>, StatusCode> {
+        if room_id == GRPC_ROOM_ID {
+            return Err(StatusCode::NOT_FOUND);
+        }
+        self.rooms.read().unwrap().get(&room_id).cloned().ok_or(StatusCode::NOT_FOUND)
+    }
+}
+
+fn now_millis() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_millis() as u64).unwrap_or_default()
+}
+
+#[derive(Deserialize)]
+struct UpdatesQuery {
+    since: Option<u64>,
+}
 
 #[tokio::main]
-async fn main() {
-    let initial_state = create_initial_state(15, 15);
-    let shared_state = Arc::new(Mutex::new(initial_state));
+async fn main() -> ExitCode {
+    let config = match Args::parse().into_config() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Configuração inválida: {}", e);
+            std::process::exit(2);
+        }
+    };
+    let telemetry = match Telemetry::init("servico_b", config.log_format, config.otlp_endpoint.as_deref()) {
+        Ok(telemetry) => telemetry,
+        Err(e) => {
+            eprintln!("Falha ao inicializar logs e tracing: {}", e);
+            std::process::exit(2);
+        }
+    };
+    let Some(session_secret) = load_session_secret(config.dev_session_secret) else {
+        telemetry.shutdown();
+        return ExitCode::from(2);
+    };
+
+    let (shared_state, restored) = match open_state(&config, session_secret) {
+        Ok(opened) => opened,
+        Err(err) => {
+            tracing::error!(error = %err, "Falha ao iniciar o serviço");
+            telemetry.shutdown();
+            return ExitCode::FAILURE;
+        }
+    };
+    if restored > 0 {
+        tracing::info!(restored, "Salas restauradas do armazenamento");
+    }
+    if !shared_state.has_room(|id| id != GRPC_ROOM_ID) {
+        shared_state.create_room(&config.default_room);
+    }
+    if !shared_state.has_room(|id| id == GRPC_ROOM_ID) {
+        shared_state.insert_room(GRPC_ROOM_ID, &config.default_room);
+    }
 
     let app = Router::new()
-        .route("/game/join", post(join_game))
-        .route("/game/state", get(get_game_state))
-        .route("/game/state/update", post(update_game_state))
-        .with_state(shared_state);
+        .route("/game/rooms", get(list_rooms).post(create_room))
+        .route("/game/{room_id}/join", post(join_game))
+        .route("/game/{room_id}/ai", post(add_ai_player))
+        .route("/game/{room_id}/state", get(get_game_state))
+        .route("/game/{room_id}/state/updates", get(get_state_updates))
+        .route("/game/{room_id}/state/update", post(update_game_state))
+        .route("/game/{room_id}/restart", post(restart_game))
+        .route("/game/{room_id}/replay", get(get_replay))
+        .route("/game/{room_id}/ws", any(game_ws))
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .route("/metrics", get(metrics::render))
+        .route_layer(middleware::from_fn_with_state(shared_state.metrics.registry.clone(), track_http))
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(TraceLayer::new_for_http().make_span_with(telemetry::request_span))
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+        .with_state(shared_state.clone());
+
+    let ai_ticks = tokio::spawn(ai::run_ai_ticks(shared_state.clone()));
+
+    tracing::info!(listen = %config.listen, "Serviço B (Estado) rodando");
+    let listener = match tokio::net::TcpListener::bind(config.listen).await {
+        Ok(listener) => listener,
+        Err(err) => {
+            tracing::error!(error = %err, listen = %config.listen, "Falha ao abrir a porta HTTP");
+            shared_state.shutdown.send_replace(true);
+            let _ = ai_ticks.await;
+            telemetry.shutdown();
+            return ExitCode::FAILURE;
+        }
+    };
+    let server = axum::serve(listener, app).with_graceful_shutdown(begin_shutdown(shared_state.clone()));
+    let grpc_server = grpc_server::serve(shared_state.clone(), config.grpc_listen);
+    let mut shutdown = shared_state.shutdown.subscribe();
+    let served = tokio::select! {
+        result = async { tokio::try_join!(async { server.await.map_err(|err| err.to_string()) }, grpc_server) } => {
+            result.map(|_| ())
+        }
+        _ = async {
+            wait_for_shutdown(&mut shutdown).await;
+            tokio::time::sleep(SHUTDOWN_DRAIN_TIMEOUT).await;
+        } => Err(format!("requisições em andamento não terminaram em {:?}", SHUTDOWN_DRAIN_TIMEOUT)),
+    };
 
-    let addr: SocketAddr = "0.0.0.0:3001".parse().unwrap();
-    println!("Serviço B (Estado) rodando em http://{}", addr);
-    let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
-    axum::serve(listener, app).await.unwrap();
+    shared_state.shutdown.send_replace(true);
+    let _ = ai_ticks.await;
+
+    let mut exit_code = ExitCode::SUCCESS;
+    if let Err(err) = served {
+        tracing::error!(error = %err, "Servidor encerrado com erro");
+        exit_code = ExitCode::FAILURE;
+    }
+    match shared_state.flush() {
+        Ok(rooms) => tracing::info!(rooms, "Estado das salas gravado; encerrando"),
+        Err(err) => {
+            tracing::error!(error = %err, "Falha ao gravar o estado das salas");
+            exit_code = ExitCode::FAILURE;
+        }
+    }
+    telemetry.shutdown();
+    exit_code
+}
+
+// ===================================================================================
+// ENCERRAMENTO
+// ===================================================================================
+
+fn open_state(config: &Config, session_secret: Vec<u8>) -> Result<(SharedState, usize), String> {
+    let storage: Arc<dyn StateStorage> = match &config.data_dir {
+        Some(dir) => Arc::new(
+            FileStorage::open(dir).map_err(|e| format!("Falha ao abrir diretório de dados: {}", e))?,
+        ),
+        None => {
+            tracing::warn!("Diretório de dados não definido; o estado das salas não será persistido");
+            Arc::new(MemoryStorage)
+        }
+    };
+    let storage =
+        StorageWriter::spawn(storage).map_err(|e| format!("Falha ao iniciar o gravador de estado: {}", e))?;
+
+    let palette = config.default_room.palette.clone();
+    let metrics = Metrics::new().map_err(|e| format!("Falha ao registrar métricas: {}", e))?;
+    let shared_state = Arc::new(AppState::new(session_secret, storage, config.limits, palette, metrics));
+    let restored = shared_state.restore_rooms().map_err(|e| format!("Falha ao carregar estado persistido: {}", e))?;
+    Ok((shared_state, restored))
+}
+
+async fn shutdown_signal() -> &'static str {
+    let interrupt = async {
+        tokio::signal::ctrl_c().await.expect("Falha ao instalar o tratador de SIGINT");
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate())
+            .expect("Falha ao instalar o tratador de SIGTERM")
+            .recv()
+            .await;
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = interrupt => "SIGINT",
+        _ = terminate => "SIGTERM",
+    }
+}
+
+async fn wait_for_shutdown(shutdown: &mut watch::Receiver<bool>) {
+    let _ = shutdown.wait_for(|shutting_down| *shutting_down).await;
+}
+
+async fn begin_shutdown(state: SharedState) {
+    let signal = shutdown_signal().await;
+    tracing::info!(signal, "Encerrando: aguardando requisições em andamento");
+    state.shutdown.send_replace(true);
 }
 
 // ===================================================================================
 // HANDLERS DAS ROTAS DA API
 // ===================================================================================
 
-async fn get_game_state(State(state): State<Arc<Mutex<GameState>>>) -> Json<GameState> {
-    let game = state.lock().unwrap();
-    Json(game.clone())
+async fn healthz() -> &'static str {
+    "ok"
+}
+
+async fn readyz(State(state): State<SharedState>) -> Result<Json<Value>, (StatusCode, Json<Value>)> {
+    if *state.shutdown.borrow() {
+        return Err((StatusCode::SERVICE_UNAVAILABLE, Json(json!({ "erro": "Serviço encerrando" }))));
+    }
+    match state.rooms.read() {
+        Ok(rooms) => Ok(Json(json!({ "status": "ok", "salas": rooms.len() }))),
+        Err(_) => Err((StatusCode::SERVICE_UNAVAILABLE, Json(json!({ "erro": "Estado das salas corrompido" })))),
+    }
+}
+
+async fn list_rooms(State(state): State<SharedState>) -> Json<Vec<RoomSummary>> {
+    let rooms = state.rooms.read().unwrap();
+    let mut summaries: Vec<_> = rooms
+        .iter()
+        .filter(|(&id, _)| id != GRPC_ROOM_ID)
+        .map(|(&id, room)| RoomSummary::new(id, &room.lock().game))
+        .filter(RoomSummary::is_open)
+        .collect();
+    summaries.sort_by_key(|summary| summary.id);
+    Json(summaries)
 }
 
-async fn join_game(State(state): State<Arc<Mutex<GameState>>>) -> Result<Json<Player>, StatusCode> {
-    let mut game = state.lock().unwrap();
+#[tracing::instrument(skip_all)]
+async fn create_room(
+    State(state): State<SharedState>,
+    Json(mut config): Json<RoomConfig>,
+) -> Result<(StatusCode, Json<RoomSummary>), (StatusCode, Json<Value>)> {
+    if config.palette.is_empty() {
+        config.palette = state.palette.clone();
+    }
+    config
+        .validate_within(&state.limits)
+        .map_err(|err| (StatusCode::BAD_REQUEST, Json(json!({ "erro": err.to_string() }))))?;
+
+    let room_id = state.create_room(&config);
+    tracing::info!(
+        room_id,
+        width = config.width,
+        height = config.height,
+        min_players = config.min_players,
+        max_players = config.max_players,
+        "Sala criada"
+    );
+    Ok((StatusCode::CREATED, Json(RoomSummary::new(room_id, &config.initial_state()))))
+}
 
-    if game.status != GameStatus::WaitingForPlayers {
-        return Err(StatusCode::FORBIDDEN); 
+#[tracing::instrument(level = "debug", skip_all, fields(room_id = room_id))]
+async fn get_game_state(
+    State(state): State<SharedState>,
+    Path(room_id): Path<RoomId>,
+) -> Result<Json<GameState>, StatusCode> {
+    let room = state.room(room_id)?;
+    let store = room.lock();
+    Ok(Json(store.game.clone()))
+}
+
+#[tracing::instrument(level = "debug", skip_all, fields(room_id = room_id))]
+async fn get_state_updates(
+    State(state): State<SharedState>,
+    Path(room_id): Path<RoomId>,
+    Query(query): Query<UpdatesQuery>,
+) -> Result<Json<StateUpdate>, StatusCode> {
+    let room = state.room(room_id)?;
+    let store = room.lock();
+    match query.since {
+        Some(version) => Ok(Json(store.history.updates_since(version, &store.game))),
+        None => Ok(Json(StateUpdate::Snapshot(store.game.clone()))),
     }
+}
 
-    let positions = [
-        (game.width / 2, 0), (game.width / 2, game.height - 1),
-        (0, game.height / 2), (game.width - 1, game.height / 2),
-    ];
-    let colors = ["#FF5733", "#33C4FF", "#A2FF33", "#F733FF"];
-    let next_player_id = (game.players.len() + 1) as PlayerId;
+#[tracing::instrument(level = "debug", skip_all, fields(room_id = room_id))]
+async fn get_replay(
+    State(state): State<SharedState>,
+    Path(room_id): Path<RoomId>,
+) -> Result<Json<ReplayExport>, StatusCode> {
+    let room = state.room(room_id)?;
+    let store = room.lock();
+    Ok(Json(ReplayExport::new(room_id, &store.game, store.events.clone())))
+}
+
+#[tracing::instrument(skip_all, fields(room_id = room_id))]
+async fn join_game(
+    State(state): State<SharedState>,
+    Path(room_id): Path<RoomId>,
+) -> Result<Json<JoinResponse>, StatusCode> {
+    let room = state.room(room_id)?;
+    let mut store = room.lock();
+    let previous = store.game.clone();
 
-    if next_player_id > 4 {
-        return Err(StatusCode::FORBIDDEN);
+    match store.game.join_player() {
+        Ok(player) => {
+            room.commit(&mut store, &previous);
+            let claims = SessionClaims { room_id, player_id: player.id, epoch: store.game.epoch };
+            let token = issue_session_token(&state.session_secret, claims, rand::random());
+            Ok(Json(JoinResponse { room_id, player, token }))
+        }
+        Err(JoinError::GameAlreadyStarted | JoinError::GameFull) => Err(StatusCode::FORBIDDEN),
     }
+}
+
+#[tracing::instrument(skip_all, fields(room_id = room_id, difficulty = %request.difficulty))]
+async fn add_ai_player(
+    State(state): State<SharedState>,
+    Path(room_id): Path<RoomId>,
+    Json(request): Json<AddAiRequest>,
+) -> Result<Json<Player>, (StatusCode, Json<Value>)> {
+    let room = state.room(room_id).map_err(|status| (status, Json(json!({ "erro": "Sala não encontrada" }))))?;
+    let mut store = room.lock();
+    let previous = store.game.clone();
+
+    let player = store
+        .game
+        .join_player()
+        .map_err(|err| (StatusCode::FORBIDDEN, Json(json!({ "erro": err.to_string() }))))?;
+    let seed = request.seed.unwrap_or_else(rand::random);
+    store.ai_players.push(AiPlayer::new(AiSeat { player_id: player.id, difficulty: request.difficulty, seed }));
+    room.commit(&mut store, &previous);
+
+    tracing::info!(difficulty = %request.difficulty, player_id = player.id, "IA ocupou uma vaga");
+    Ok(Json(player))
+}
+
+fn verify_room_session(
+    state: &AppState,
+    headers: &HeaderMap,
+    room_id: RoomId,
+    game: &GameState,
+) -> Result<SessionClaims, SessionError> {
+    let token = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "))
+        .ok_or(SessionError::MissingToken)?;
 
-    let pos = positions[(next_player_id - 1) as usize];
-    let color = colors[(next_player_id - 1) as usize];
+    let claims = verify_session_token(&state.session_secret, token)?;
+    if claims.room_id != room_id {
+        return Err(SessionError::WrongRoom);
+    }
+    claims.check_epoch(game)?;
+    Ok(claims)
+}
 
-    let new_player = Player { id: next_player_id, x: pos.0, y: pos.1, color: color.to_string() };
+#[tracing::instrument(skip_all, fields(room_id = room_id))]
+async fn restart_game(
+    State(state): State<SharedState>,
+    Path(room_id): Path<RoomId>,
+    headers: HeaderMap,
+    Json(request): Json<RestartRequest>,
+) -> Result<Json<GameState>, (StatusCode, Json<Value>)> {
+    let room = state.room(room_id).map_err(|status| (status, Json(json!({ "erro": "Sala não encontrada" }))))?;
+    let mut store = room.lock();
 
-    game.players.insert(next_player_id, new_player.clone());
-    game.grid[pos.1][pos.0] = CellState::Owned(next_player_id);
+    let claims = verify_room_session(&state, &headers, room_id, &store.game)
+        .map_err(|err| (StatusCode::UNAUTHORIZED, Json(json!({ "erro": err.to_string() }))))?;
 
-    if game.players.len() == 2 {
-        game.status = GameStatus::InProgress;
+    let previous = store.game.clone();
+    store
+        .game
+        .restart(request.keep_players)
+        .map_err(|err| (StatusCode::CONFLICT, Json(json!({ "erro": err.to_string() }))))?;
+    if !request.keep_players {
+        store.ai_players.clear();
     }
+    room.commit(&mut store, &previous);
 
-    Ok(Json(new_player))
+    tracing::info!(player_id = claims.player_id, keep_players = request.keep_players, "Sala reiniciada");
+    Ok(Json(store.game.clone()))
 }
 
+#[tracing::instrument(skip_all, fields(room_id = room_id, from_version = delta.from_version))]
 async fn update_game_state(
-    State(state): State<Arc<Mutex<GameState>>>,
-    Json(new_state): Json<GameState>,
-) -> StatusCode {
-    let mut game = state.lock().unwrap();
-    *game = new_state;
-    StatusCode::OK
+    State(state): State<SharedState>,
+    Path(room_id): Path<RoomId>,
+    Json(delta): Json<StateDelta>,
+) -> Result<Json<UpdateAccepted>, (StatusCode, Json<Value>)> {
+    let room = state.room(room_id).map_err(|status| (status, Json(json!({ "erro": "Sala não encontrada" }))))?;
+    let mut store = room.lock();
+
+    if delta.from_version != store.game.version {
+        state.metrics.move_rejected("servico_a", "VERSION_CONFLICT");
+        let conflict = VersionConflict { expected_version: delta.from_version, current_version: store.game.version };
+        tracing::debug!(current_version = store.game.version, "Delta recusado por versão desatualizada");
+        return Err((StatusCode::CONFLICT, Json(json!(conflict))));
+    }
+
+    let previous = store.game.clone();
+    store.game.apply_delta(&delta);
+    room.commit(&mut store, &previous);
+    state.metrics.move_accepted("servico_a");
+    tracing::debug!(version = store.game.version, "Delta aplicado");
+    Ok(Json(UpdateAccepted { version: store.game.version }))
+}
+
+#[tracing::instrument(level = "debug", skip_all, fields(room_id = room_id))]
+async fn game_ws(
+    ws: WebSocketUpgrade,
+    State(state): State<SharedState>,
+    Path(room_id): Path<RoomId>,
+) -> Result<Response, StatusCode> {
+    let room = state.room(room_id)?;
+    let shutdown = state.shutdown.subscribe();
+    Ok(ws.on_upgrade(move |socket| push_game_state(socket, room, shutdown)))
 }
 
 // ===================================================================================
-// FUNÇÃO DE INICIALIZAÇÃO
+// PUSH DE ESTADO VIA WEBSOCKET
 // ===================================================================================
 
-fn create_initial_state(width: usize, height: usize) -> GameState {
-    GameState {
-        status: GameStatus::WaitingForPlayers,
-        width,
-        height,
-        grid: vec![vec![CellState::Neutral; width]; height],
-        players: HashMap::new(),
+async fn push_game_state(mut socket: WebSocket, room: Arc<Room>, mut shutdown: watch::Receiver<bool>) {
+    let mut updates = room.updates.subscribe();
+    let mut last_version = None;
+
+    loop {
+        updates.mark_unchanged();
+        let update = {
+            let store = room.lock();
+            match last_version {
+                Some(version) => store.history.updates_since(version, &store.game),
+                None => StateUpdate::Snapshot(store.game.clone()),
+            }
+        };
+        last_version = Some(update.version());
+
+        let payload = match serde_json::to_string(&update) {
+            Ok(payload) => payload,
+            Err(err) => {
+                tracing::error!(error = %err, "Erro ao serializar atualização de estado");
+                return;
+            }
+        };
+        if socket.send(Message::text(payload)).await.is_err() {
+            return;
+        }
+
+        loop {
+            tokio::select! {
+                changed = updates.changed() => match changed {
+                    Ok(()) => break,
+                    Err(_) => return,
+                },
+                _ = wait_for_shutdown(&mut shutdown) => {
+                    let frame = CloseFrame { code: close_code::RESTART, reason: "Servidor reiniciando".into() };
+                    let _ = socket.send(Message::Close(Some(frame))).await;
+                    return;
+                }
+                incoming = socket.recv() => match incoming {
+                    Some(Ok(Message::Close(_))) | Some(Err(_)) | None => return,
+                    Some(Ok(_)) => {}
+                },
+            }
+        }
     }
 }
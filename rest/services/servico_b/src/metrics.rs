@@ -0,0 +1,71 @@
+use axum::{extract::State, response::Response};
+use game_kernel::*;
+use prometheus::{Histogram, IntCounterVec, IntGaugeVec, Opts};
+use servico_comum::metrics::MetricsRegistry;
+
+use crate::SharedState;
+
+pub struct Metrics {
+    pub registry: MetricsRegistry,
+    moves_accepted: IntCounterVec,
+    moves_rejected: IntCounterVec,
+    rooms: IntGaugeVec,
+    players: IntGaugeVec,
+    pub room_lock_wait: Histogram,
+}
+
+impl Metrics {
+    pub fn new() -> prometheus::Result<Self> {
+        let registry = MetricsRegistry::new("servico_b")?;
+        let moves_accepted = registry.register(IntCounterVec::new(
+            Opts::new("moves_accepted_total", "Movimentos aplicados ao estado"),
+            &["source"],
+        )?)?;
+        let moves_rejected = registry.register(IntCounterVec::new(
+            Opts::new("moves_rejected_total", "Movimentos recusados"),
+            &["source", "reason"],
+        )?)?;
+        let rooms = registry.register(IntGaugeVec::new(Opts::new("rooms", "Salas por status da partida"), &["status"])?)?;
+        let players = registry.register(IntGaugeVec::new(Opts::new("players", "Jogadores nas salas"), &["kind"])?)?;
+        let room_lock_wait =
+            registry.lock_wait_histogram("room_lock_wait_seconds", "Tempo de espera pelo mutex de uma sala")?;
+
+        Ok(Metrics { registry, moves_accepted, moves_rejected, rooms, players, room_lock_wait })
+    }
+
+    pub fn move_accepted(&self, source: &str) {
+        self.moves_accepted.with_label_values(&[source]).inc();
+    }
+
+    pub fn move_rejected(&self, source: &str, reason: &str) {
+        self.moves_rejected.with_label_values(&[source, reason]).inc();
+    }
+}
+
+// ===================================================================================
+// EXPOSIÇÃO
+// ===================================================================================
+
+pub async fn render(State(state): State<SharedState>) -> Response {
+    let metrics = &state.metrics;
+    let rooms: Vec<_> = state.rooms.read().unwrap().values().cloned().collect();
+
+    let mut by_status = [(GameStatus::WaitingForPlayers, 0), (GameStatus::InProgress, 0), (GameStatus::Finished, 0)];
+    let (mut humans, mut ais) = (0, 0);
+    for room in rooms {
+        let store = room.lock();
+        if let Some((_, count)) = by_status.iter_mut().find(|(status, _)| *status == store.game.status) {
+            *count += 1;
+        }
+        ais += store.ai_players.len() as i64;
+        humans += store.game.players.len() as i64 - store.ai_players.len() as i64;
+    }
+
+    for (status, count) in by_status {
+        metrics.rooms.with_label_values(&[&format!("{:?}", status)]).set(count);
+    }
+    metrics.players.with_label_values(&["human"]).set(humans);
+    metrics.players.with_label_values(&["ai"]).set(ais);
+
+    metrics.registry.render()
+}
@@ -0,0 +1,257 @@
+use game_kernel::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc};
+use std::thread;
+
+use crate::ai::AiSeat;
+
+pub struct SavedRoom {
+    pub id: RoomId,
+    pub game: GameState,
+    pub ai_seats: Vec<AiSeat>,
+    pub events: Vec<GameEvent>,
+}
+
+pub trait StateStorage: Send + Sync {
+    fn save_room(&self, room_id: RoomId, game: &GameState, ai_seats: &[AiSeat]) -> io::Result<()>;
+    fn append_events(&self, room_id: RoomId, events: &[GameEvent]) -> io::Result<()>;
+    fn load_rooms(&self) -> io::Result<Vec<SavedRoom>>;
+    fn flush(&self) -> io::Result<()>;
+}
+
+// ===================================================================================
+// ARMAZENAMENTO EM MEMÓRIA (SEM PERSISTÊNCIA)
+// ===================================================================================
+
+pub struct MemoryStorage;
+
+impl StateStorage for MemoryStorage {
+    fn save_room(&self, _room_id: RoomId, _game: &GameState, _ai_seats: &[AiSeat]) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn append_events(&self, _room_id: RoomId, _events: &[GameEvent]) -> io::Result<()> {
+        Ok(())
+    }
+
+    fn load_rooms(&self) -> io::Result<Vec<SavedRoom>> {
+        Ok(Vec::new())
+    }
+
+    fn flush(&self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+// ===================================================================================
+// ARMAZENAMENTO EM ARQUIVOS
+// ===================================================================================
+
+#[derive(Serialize)]
+struct RoomFileRef<'a> {
+    game: &'a GameState,
+    ai_seats: &'a [AiSeat],
+}
+
+#[derive(Deserialize)]
+struct RoomFile {
+    game: GameState,
+    #[serde(default)]
+    ai_seats: Vec<AiSeat>,
+}
+
+pub struct FileStorage {
+    dir: PathBuf,
+}
+
+impl FileStorage {
+    pub fn open(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(FileStorage { dir })
+    }
+
+    fn room_path(&self, room_id: RoomId) -> PathBuf {
+        self.dir.join(format!("sala-{}.json", room_id))
+    }
+
+    fn events_path(&self, room_id: RoomId) -> PathBuf {
+        self.dir.join(format!("sala-{}.eventos.jsonl", room_id))
+    }
+
+    fn load_events(&self, room_id: RoomId) -> io::Result<Vec<GameEvent>> {
+        let file = match fs::File::open(self.events_path(room_id)) {
+            Ok(file) => file,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+
+        let mut events = Vec::new();
+        for line in BufReader::new(file).lines() {
+            match serde_json::from_str(&line?) {
+                Ok(event) => events.push(event),
+                Err(err) => {
+                    tracing::warn!(room_id, error = %err, "Registro de eventos da sala truncado");
+                    break;
+                }
+            }
+        }
+        Ok(events)
+    }
+}
+
+impl StateStorage for FileStorage {
+    // O arquivo temporário e o diretório são sincronizados antes e depois do rename: uma sala
+    // gravada sobrevive a uma queda do sistema. Os eventos são apenas anexados e só são
+    // sincronizados no `flush`.
+    fn save_room(&self, room_id: RoomId, game: &GameState, ai_seats: &[AiSeat]) -> io::Result<()> {
+        let path = self.room_path(room_id);
+        let tmp_path = path.with_extension("json.tmp");
+        let mut tmp = File::create(&tmp_path)?;
+        tmp.write_all(&serde_json::to_vec(&RoomFileRef { game, ai_seats })?)?;
+        tmp.sync_all()?;
+        fs::rename(tmp_path, path)?;
+        File::open(&self.dir)?.sync_all()
+    }
+
+    fn append_events(&self, room_id: RoomId, events: &[GameEvent]) -> io::Result<()> {
+        let mut lines = Vec::new();
+        for event in events {
+            serde_json::to_writer(&mut lines, event)?;
+            lines.push(b'\n');
+        }
+        let mut file = OpenOptions::new().create(true).append(true).open(self.events_path(room_id))?;
+        file.write_all(&lines)
+    }
+
+    fn load_rooms(&self) -> io::Result<Vec<SavedRoom>> {
+        let mut rooms = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            let Some(room_id) = path
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.strip_prefix("sala-")?.strip_suffix(".json"))
+                .and_then(|id| id.parse().ok())
+            else {
+                continue;
+            };
+
+            match serde_json::from_slice::<RoomFile>(&fs::read(&path)?) {
+                Ok(file) => rooms.push(SavedRoom {
+                    id: room_id,
+                    game: file.game,
+                    ai_seats: file.ai_seats,
+                    events: self.load_events(room_id)?,
+                }),
+                Err(err) => tracing::warn!(path = %path.display(), error = %err, "Ignorando estado corrompido"),
+            }
+        }
+        rooms.sort_by_key(|room| room.id);
+        Ok(rooms)
+    }
+
+    fn flush(&self) -> io::Result<()> {
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            if path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.starts_with("sala-")) {
+                File::open(&path)?.sync_all()?;
+            }
+        }
+        File::open(&self.dir)?.sync_all()
+    }
+}
+
+// ===================================================================================
+// GRAVAÇÃO FORA DO LOCK DAS SALAS
+// ===================================================================================
+
+enum WriteOp {
+    Room { room_id: RoomId, game: GameState, ai_seats: Vec<AiSeat> },
+    Events { room_id: RoomId, events: Vec<GameEvent> },
+    Flush(mpsc::SyncSender<io::Result<()>>),
+}
+
+// As salas só copiam o estado para a fila; uma thread dedicada faz o I/O, gravando apenas o
+// estado mais recente de cada sala quando as alterações chegam mais rápido que o disco.
+#[derive(Clone)]
+pub struct StorageWriter {
+    backend: Arc<dyn StateStorage>,
+    sender: mpsc::Sender<WriteOp>,
+}
+
+impl StorageWriter {
+    pub fn spawn(backend: Arc<dyn StateStorage>) -> io::Result<Self> {
+        let (sender, receiver) = mpsc::channel();
+        let worker = backend.clone();
+        thread::Builder::new()
+            .name("gravador-de-estado".to_string())
+            .spawn(move || run_writer(worker.as_ref(), receiver))?;
+        Ok(StorageWriter { backend, sender })
+    }
+
+    pub fn load_rooms(&self) -> io::Result<Vec<SavedRoom>> {
+        self.backend.load_rooms()
+    }
+
+    pub fn save_room(&self, room_id: RoomId, game: GameState, ai_seats: Vec<AiSeat>) {
+        self.send(WriteOp::Room { room_id, game, ai_seats });
+    }
+
+    pub fn append_events(&self, room_id: RoomId, events: Vec<GameEvent>) {
+        if !events.is_empty() {
+            self.send(WriteOp::Events { room_id, events });
+        }
+    }
+
+    // Espera a fila esvaziar e sincroniza tudo com o disco.
+    pub fn flush(&self) -> io::Result<()> {
+        let (reply, done) = mpsc::sync_channel(1);
+        self.send(WriteOp::Flush(reply));
+        done.recv().unwrap_or_else(|_| Err(io::Error::other("o gravador de estado foi encerrado")))
+    }
+
+    fn send(&self, op: WriteOp) {
+        if self.sender.send(op).is_err() {
+            tracing::error!("O gravador de estado foi encerrado; alteração não persistida");
+        }
+    }
+}
+
+fn run_writer(storage: &dyn StateStorage, receiver: mpsc::Receiver<WriteOp>) {
+    while let Ok(first) = receiver.recv() {
+        let mut rooms = BTreeMap::new();
+        let mut flushes = Vec::new();
+        for op in std::iter::once(first).chain(receiver.try_iter()) {
+            match op {
+                WriteOp::Events { room_id, events } => {
+                    if let Err(err) = storage.append_events(room_id, &events) {
+                        tracing::error!(room_id, error = %err, "Erro ao registrar eventos da sala");
+                    }
+                }
+                WriteOp::Room { room_id, game, ai_seats } => {
+                    rooms.insert(room_id, (game, ai_seats));
+                }
+                WriteOp::Flush(reply) => flushes.push(reply),
+            }
+        }
+
+        let mut failed = None;
+        for (room_id, (game, ai_seats)) in rooms {
+            if let Err(err) = storage.save_room(room_id, &game, &ai_seats) {
+                tracing::error!(room_id, error = %err, "Erro ao persistir estado da sala");
+                failed = Some(err);
+            }
+        }
+        for reply in flushes {
+            let _ = reply.send(match failed.take() {
+                Some(err) => Err(err),
+                None => storage.flush(),
+            });
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use axum::{
+    extract::{MatchedPath, Request, State},
+    http::{header::CONTENT_TYPE, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use prometheus::{
+    core::Collector, exponential_buckets, Encoder, Histogram, HistogramOpts, HistogramVec, IntCounterVec, Opts,
+    Registry, TextEncoder,
+};
+use std::time::Instant;
+
+// ===================================================================================
+// REGISTRO COMPARTILHADO PELOS SERVIÇOS
+// ===================================================================================
+
+// Cada serviço cria um registro com o próprio prefixo; as métricas HTTP já vêm registradas e
+// as específicas do serviço entram com `register`.
+#[derive(Clone)]
+pub struct MetricsRegistry {
+    registry: Registry,
+    http_requests: IntCounterVec,
+    http_latency: HistogramVec,
+}
+
+impl MetricsRegistry {
+    pub fn new(service: &str) -> prometheus::Result<Self> {
+        let registry = Registry::new_custom(Some(service.to_string()), None)?;
+        let http_requests = IntCounterVec::new(
+            Opts::new("http_requests_total", "Requisições HTTP atendidas"),
+            &["method", "route", "status"],
+        )?;
+        let http_latency = HistogramVec::new(
+            HistogramOpts::new("http_request_duration_seconds", "Latência das requisições HTTP"),
+            &["method", "route"],
+        )?;
+        registry.register(Box::new(http_requests.clone()))?;
+        registry.register(Box::new(http_latency.clone()))?;
+        Ok(MetricsRegistry { registry, http_requests, http_latency })
+    }
+
+    pub fn register<C: Collector + Clone + 'static>(&self, collector: C) -> prometheus::Result<C> {
+        self.registry.register(Box::new(collector.clone()))?;
+        Ok(collector)
+    }
+
+    pub fn lock_wait_histogram(&self, name: &str, help: &str) -> prometheus::Result<Histogram> {
+        let buckets = exponential_buckets(0.000_001, 4.0, 10)?;
+        self.register(Histogram::with_opts(HistogramOpts::new(name, help).buckets(buckets))?)
+    }
+
+    pub fn render(&self) -> Response {
+        let encoder = TextEncoder::new();
+        let mut body = Vec::new();
+        match encoder.encode(&self.registry.gather(), &mut body) {
+            Ok(()) => ([(CONTENT_TYPE, encoder.format_type().to_string())], body).into_response(),
+            Err(err) => {
+                tracing::error!(error = %err, "Erro ao codificar métricas");
+                StatusCode::INTERNAL_SERVER_ERROR.into_response()
+            }
+        }
+    }
+}
+
+// ===================================================================================
+// COLETA
+// ===================================================================================
+
+pub async fn track_http(State(metrics): State<MetricsRegistry>, request: Request, next: Next) -> Response {
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map_or_else(|| "desconhecida".to_string(), |path| path.as_str().to_string());
+
+    let started = Instant::now();
+    let response = next.run(request).await;
+
+    metrics.http_latency.with_label_values(&[&method, &route]).observe(started.elapsed().as_secs_f64());
+    metrics.http_requests.with_label_values(&[&method, &route, response.status().as_str()]).inc();
+    response
+}
@@ -0,0 +1,107 @@
+use axum::{
+    extract::MatchedPath,
+    http::{HeaderMap, HeaderName, Request},
+};
+use clap::ValueEnum;
+use opentelemetry::{global, trace::TracerProvider, KeyValue};
+use opentelemetry_http::{HeaderExtractor, HeaderInjector};
+use opentelemetry_otlp::{SpanExporter, WithExportConfig};
+use opentelemetry_sdk::{propagation::TraceContextPropagator, trace::SdkTracerProvider, Resource};
+use serde::Deserialize;
+use tower_http::request_id::RequestId;
+use tracing::Span;
+use tracing_opentelemetry::OpenTelemetrySpanExt;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt, EnvFilter, Layer};
+
+const DEFAULT_LOG_FILTER: &str = "info";
+
+#[derive(ValueEnum, Deserialize, Clone, Copy, Default, Debug)]
+#[serde(rename_all = "lowercase")]
+pub enum LogFormat {
+    #[default]
+    Text,
+    Json,
+}
+
+pub struct Telemetry {
+    provider: Option<SdkTracerProvider>,
+}
+
+impl Telemetry {
+    pub fn init(service_name: &'static str, format: LogFormat, otlp_endpoint: Option<&str>) -> Result<Self, String> {
+        let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new(DEFAULT_LOG_FILTER));
+        let fmt_layer = match format {
+            LogFormat::Text => tracing_subscriber::fmt::layer().boxed(),
+            LogFormat::Json => tracing_subscriber::fmt::layer().json().with_current_span(true).boxed(),
+        };
+
+        let provider = otlp_endpoint.map(|endpoint| build_provider(service_name, endpoint)).transpose()?;
+        let otel_layer = provider
+            .as_ref()
+            .map(|provider| tracing_opentelemetry::layer().with_tracer(provider.tracer(service_name)));
+
+        global::set_text_map_propagator(TraceContextPropagator::new());
+        tracing_subscriber::registry()
+            .with(filter)
+            .with(fmt_layer)
+            .with(otel_layer)
+            .try_init()
+            .map_err(|e| e.to_string())?;
+
+        if let Some(endpoint) = otlp_endpoint {
+            tracing::info!(endpoint, "Exportando spans via OTLP");
+        }
+        Ok(Telemetry { provider })
+    }
+
+    pub fn shutdown(self) {
+        if let Some(provider) = self.provider {
+            if let Err(err) = provider.shutdown() {
+                eprintln!("Erro ao enviar os últimos spans: {}", err);
+            }
+        }
+    }
+}
+
+pub fn validate_otlp_endpoint(endpoint: String) -> Result<String, String> {
+    if endpoint.starts_with("http://") || endpoint.starts_with("https://") {
+        Ok(endpoint)
+    } else {
+        Err(format!("Endpoint OTLP inválido '{}': use o formato http://host:porta", endpoint))
+    }
+}
+
+fn build_provider(service_name: &'static str, endpoint: &str) -> Result<SdkTracerProvider, String> {
+    let exporter = SpanExporter::builder()
+        .with_http()
+        .with_endpoint(format!("{}/v1/traces", endpoint.trim_end_matches('/')))
+        .build()
+        .map_err(|e| format!("exportador OTLP inválido: {}", e))?;
+
+    let resource = Resource::builder().with_attribute(KeyValue::new("service.name", service_name)).build();
+    Ok(SdkTracerProvider::builder().with_batch_exporter(exporter).with_resource(resource).build())
+}
+
+// ===================================================================================
+// SPANS DE REQUISIÇÃO E PROPAGAÇÃO
+// ===================================================================================
+
+pub const X_REQUEST_ID: HeaderName = HeaderName::from_static("x-request-id");
+
+pub fn request_span<B>(request: &Request<B>) -> Span {
+    let route = request.extensions().get::<MatchedPath>().map_or(request.uri().path(), MatchedPath::as_str);
+    let request_id = request.headers().get(&X_REQUEST_ID).and_then(|value| value.to_str().ok()).unwrap_or_default();
+    let span = tracing::info_span!("http", method = %request.method(), route, request_id);
+
+    let parent = global::get_text_map_propagator(|propagator| propagator.extract(&HeaderExtractor(request.headers())));
+    let _ = span.set_parent(parent);
+    span
+}
+
+pub fn trace_headers(request_id: &RequestId) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    let context = Span::current().context();
+    global::get_text_map_propagator(|propagator| propagator.inject_context(&context, &mut HeaderInjector(&mut headers)));
+    headers.insert(X_REQUEST_ID, request_id.header_value().clone());
+    headers
+}
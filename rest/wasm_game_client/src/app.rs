@@ -1,51 +1,50 @@
 use super::drawing::draw_game;
-use super::utils::*; 
+use super::gateway_transport::GatewayTransport;
+use super::rest_transport::RestTransport;
+use super::transport::{RestartOutcome, Transport, TransportKind};
+use super::utils::*;
 use game_kernel::*;
 use std::cell::RefCell;
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
-use web_sys::KeyboardEvent;
+use web_sys::{CloseEvent, KeyboardEvent, MessageEvent, WebSocket};
 
 
 #[wasm_bindgen]
 pub struct App {
-    api_base_url: String,
-    client: reqwest::Client,
+    transport: Rc<dyn Transport>,
     my_player: Rc<RefCell<Option<Player>>>,
     game_state: Rc<RefCell<Option<GameState>>>,
 }
 
 fn setup_keyboard_listener(app: Rc<App>) -> Result<(), JsValue> {
     let keydown_callback = Closure::<dyn FnMut(_)>::new(move |event: KeyboardEvent| {
-        if let Some(state) = app.game_state.borrow().as_ref() {
-            if state.status != GameStatus::InProgress {
-                return;
-            }
-        } else {
-            return;
-        }
-
         let mut direction = None;
         match event.key().as_str() {
-            "w" | "ArrowUp" => direction = Some("UP"),
-            "s" | "ArrowDown" => direction = Some("DOWN"),
-            "a" | "ArrowLeft" => direction = Some("LEFT"),
-            "d" | "ArrowRight" => direction = Some("RIGHT"),
+            "w" | "ArrowUp" => direction = Some(Direction::Up),
+            "s" | "ArrowDown" => direction = Some(Direction::Down),
+            "a" | "ArrowLeft" => direction = Some(Direction::Left),
+            "d" | "ArrowRight" => direction = Some(Direction::Right),
             _ => {}
         }
 
         if let (Some(dir), Some(player)) = (direction, app.my_player.borrow().as_ref()) {
             event.prevent_default();
-            let payload = MovePayload { player_id: player.id, direction: dir.to_string() };
-            let client_clone = app.client.clone();
-            let api_base_url = app.api_base_url.clone();
+            match app.game_state.borrow().as_ref().map(|state| state.resolve_move(player.id, dir)) {
+                Some(Ok(_)) => {}
+                Some(Err(err)) => {
+                    log(&format!("Movimento recusado: {}", err));
+                    return;
+                }
+                None => return,
+            }
+            let transport = app.transport.clone();
+            let player_id = player.id;
             spawn_local(async move {
-                let _ = client_clone
-                    .post(format!("{}/game/move", api_base_url))
-                    .json(&payload)
-                    .send()
-                    .await;
+                if let Err(err) = transport.send_move(player_id, dir).await {
+                    log(&format!("Movimento recusado pelo servidor: {}", err));
+                }
             });
         }
     });
@@ -60,10 +59,8 @@ fn setup_drawing_loop(app: Rc<App>) {
     let g = drawing_loop_callback.clone();
 
     *g.borrow_mut() = Some(Closure::<dyn FnMut()>::new(move || {
-        if let Some(state) = app.game_state.borrow().as_ref() {
-            if let Some(player) = app.my_player.borrow().as_ref() {
-                draw_game(&get_canvas_context(), state, player.id);
-            }
+        if let (Some(state), Some(player)) = (app.game_state.borrow().as_ref(), app.my_player.borrow().as_ref()) {
+            draw_game(&get_canvas_context(), state, player.id, app.transport.supports_ai());
         }
         request_animation_frame(drawing_loop_callback.borrow().as_ref().unwrap());
     }));
@@ -81,25 +78,24 @@ fn setup_polling_loop(app: Rc<App>) {
 
         spawn_local(async move {
             let mut next_delay_ms = 1000;
-            let url = format!("{}/game/state", app_clone.api_base_url);
-
-            if let Ok(resp) = app_clone.client.get(&url).send().await {
-                if let Ok(state) = resp.json::<GameState>().await {
-                    match state.status {
-                        GameStatus::WaitingForPlayers => {
-                            log("Aguardando mais jogadores para começar...");
-                            next_delay_ms = 2000;
-                        },
-                        GameStatus::InProgress => {
-                            next_delay_ms = 2;
-                        },
-                        GameStatus::Finished => {
-                            log("Jogo encerrado. Parando requisições.");
-                            *app_clone.game_state.borrow_mut() = Some(state);
-                            return;
-                        }
+            let since = app_clone.game_state.borrow().as_ref().map(|state| state.version);
+
+            if let Ok(update) = app_clone.transport.fetch_update(since).await {
+                let applied = update.apply_to(&mut app_clone.game_state.borrow_mut());
+                let status = app_clone.game_state.borrow().as_ref().map(|state| state.status);
+
+                match status.filter(|_| applied) {
+                    Some(GameStatus::WaitingForPlayers) => {
+                        log("Aguardando mais jogadores para começar...");
+                        next_delay_ms = 2000;
+                    },
+                    Some(GameStatus::InProgress) => {
+                        next_delay_ms = app_clone.transport.in_progress_poll_ms();
+                    },
+                    Some(GameStatus::Finished) => {
+                        next_delay_ms = 2000;
                     }
-                    *app_clone.game_state.borrow_mut() = Some(state);
+                    None => {}
                 }
             }
 
@@ -114,42 +110,111 @@ fn setup_polling_loop(app: Rc<App>) {
     }
 }
 
+fn setup_restart_button(app: Rc<App>) -> Result<(), JsValue> {
+    let restart_callback = Closure::<dyn FnMut()>::new(move || {
+        let transport = app.transport.clone();
+        spawn_local(async move {
+            match transport.restart().await {
+                Ok(RestartOutcome::SamePlayers) => log("Nova partida iniciada com os mesmos jogadores!"),
+                Ok(RestartOutcome::Rejoin) => {
+                    log("Jogo reiniciado com sucesso! Recarregando a página...");
+                    window().location().reload().unwrap();
+                }
+                Err(err) => log(&format!("Erro ao reiniciar o jogo: {}", err)),
+            }
+        });
+    });
+
+    let restart_button = document().get_element_by_id("restart-button").unwrap();
+    restart_button.add_event_listener_with_callback("click", restart_callback.as_ref().unchecked_ref())?;
+    restart_callback.forget();
+    Ok(())
+}
+
+fn setup_ai_button(app: Rc<App>) -> Result<(), JsValue> {
+    let ai_callback = Closure::<dyn FnMut()>::new(move || {
+        let transport = app.transport.clone();
+        let difficulty = query_param("ia").and_then(|value| value.parse().ok()).unwrap_or(Difficulty::Medium);
+        spawn_local(async move {
+            while let Ok(player) = transport.add_ai_player(difficulty).await {
+                log(&format!("IA '{}' entrou como Jogador {}", difficulty, player.id));
+            }
+        });
+    });
+
+    let ai_button = document().get_element_by_id("ai-button").unwrap();
+    ai_button.add_event_listener_with_callback("click", ai_callback.as_ref().unchecked_ref())?;
+    ai_callback.forget();
+    Ok(())
+}
+
+fn setup_push_channel(app: Rc<App>) {
+    let Some(ws_url) = app.transport.push_url() else {
+        setup_polling_loop(app);
+        return;
+    };
+    let socket = match WebSocket::new(&ws_url) {
+        Ok(socket) => socket,
+        Err(_) => {
+            log("WebSocket indisponível. Usando polling.");
+            setup_polling_loop(app);
+            return;
+        }
+    };
+
+    let app_clone = app.clone();
+    let message_callback = Closure::<dyn FnMut(_)>::new(move |event: MessageEvent| {
+        if let Some(text) = event.data().as_string()
+            && let Ok(update) = serde_json::from_str::<StateUpdate>(&text)
+            && !update.apply_to(&mut app_clone.game_state.borrow_mut())
+        {
+            log("Atualização de estado fora de ordem descartada.");
+        }
+    });
+    socket.set_onmessage(Some(message_callback.as_ref().unchecked_ref()));
+    message_callback.forget();
+
+    let close_callback = Closure::once(move |event: CloseEvent| {
+        if event.reason().is_empty() {
+            log("Conexão WebSocket encerrada. Voltando ao polling.");
+        } else {
+            log(&format!("Conexão WebSocket encerrada: {}. Voltando ao polling.", event.reason()));
+        }
+        setup_polling_loop(app);
+    });
+    socket.set_onclose(Some(close_callback.as_ref().unchecked_ref()));
+    close_callback.forget();
+}
+
 #[wasm_bindgen]
 impl App {
     #[wasm_bindgen(constructor)]
-    pub fn new(api_base_url: String) -> Self {
-        App {
-            api_base_url,
-            client: reqwest::Client::new(),
+    pub fn new(api_base_url: String, transport: String) -> Result<App, JsValue> {
+        let transport: Rc<dyn Transport> = match transport.parse().map_err(|e: String| JsValue::from_str(&e))? {
+            TransportKind::Rest => Rc::new(RestTransport::new(api_base_url)),
+            TransportKind::Grpc => Rc::new(GatewayTransport::new(api_base_url)),
+        };
+        Ok(App {
+            transport,
             my_player: Rc::new(RefCell::new(None)),
             game_state: Rc::new(RefCell::new(None)),
-        }
+        })
     }
 
     #[wasm_bindgen]
     pub async fn run_app(self) -> Result<(), JsValue> {
-        log("Tentando entrar no jogo em /game/join...");
-        
-        let resp = self.client.post(format!("{}/game/join", self.api_base_url)).send().await
-            .map_err(|e| JsValue::from_str(&format!("Erro de rede ao tentar /join: {}", e)))?;
-        
-        if resp.status().is_success() {
-            let player = resp.json::<Player>().await
-                .map_err(|e| JsValue::from_str(&format!("Erro ao decodificar JSON do jogador: {}", e)))?;
-            log(&format!("Entrou com sucesso como Jogador {}", player.id));
-            *self.my_player.borrow_mut() = Some(player);
-        } else {
-            let err_text = resp.text().await
-                .map_err(|e| JsValue::from_str(&format!("Erro ao ler corpo da resposta de erro: {}", e)))?;
-            return Err(JsValue::from_str(&format!("Falha ao entrar no jogo: {}", err_text)));
-        }
-        
+        let player = self.transport.join().await.map_err(|e| JsValue::from_str(&e))?;
+        log(&format!("Entrou com sucesso como Jogador {}", player.id));
+        *self.my_player.borrow_mut() = Some(player);
+
         let app_rc = Rc::new(self);
 
         setup_keyboard_listener(app_rc.clone())?;
+        setup_restart_button(app_rc.clone())?;
+        setup_ai_button(app_rc.clone())?;
         setup_drawing_loop(app_rc.clone());
-        setup_polling_loop(app_rc);
+        setup_push_channel(app_rc);
 
         Ok(())
     }
-}
\ No newline at end of file
+}
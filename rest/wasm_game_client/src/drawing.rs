@@ -1,10 +1,9 @@
 use super::utils::document;
 use game_kernel::*;
-use std::collections::HashMap;
 use wasm_bindgen::JsCast;
 use web_sys::CanvasRenderingContext2d;
 
-pub fn draw_game(ctx: &CanvasRenderingContext2d, state: &GameState, my_id: PlayerId) {
+pub fn draw_game(ctx: &CanvasRenderingContext2d, state: &GameState, my_id: PlayerId, ai_available: bool) {
     let canvas = ctx.canvas().unwrap();
     let cell_width = (canvas.width() as f64 / state.width as f64).max(1.0);
     let cell_height = (canvas.height() as f64 / state.height as f64).max(1.0);
@@ -47,28 +46,24 @@ pub fn draw_game(ctx: &CanvasRenderingContext2d, state: &GameState, my_id: Playe
         .dyn_into::<web_sys::HtmlElement>().unwrap();
     
     let status_text = match state.status {
-        GameStatus::WaitingForPlayers => format!("Aguardando jogadores... ({}/{})", state.players.len(), 2),
+        GameStatus::WaitingForPlayers => format!("Aguardando jogadores... ({}/{})", state.players.len(), state.min_players),
         GameStatus::InProgress => format!("Jogo em andamento! Você é o Jogador {}", my_id),
-        GameStatus::Finished => {
-            let mut scores: HashMap<PlayerId, usize> = HashMap::new();
-            for row in &state.grid {
-                for cell in row {
-                    if let CellState::Owned(id) = cell {
-                        *scores.entry(*id).or_insert(0) += 1;
-                    }
-                }
-            }
-            let winner = scores.iter().max_by_key(|&(_, score)| score);
-            
-            if let Some((id, _)) = winner {
-                format!("Fim de jogo! Vencedor: Jogador {}", id)
-            }
-            else {
-                "Fim de jogo!".to_string()
-            }
-        }
+        GameStatus::Finished => match state.winner() {
+            Some(id) => format!("Fim de jogo! Vencedor: Jogador {}", id),
+            None => "Fim de jogo!".to_string(),
+        },
     };
     status_element.set_inner_text(&status_text);
+
+    let restart_button = document().get_element_by_id("restart-button").unwrap()
+        .dyn_into::<web_sys::HtmlElement>().unwrap();
+    let restart_display = if state.status == GameStatus::Finished { "block" } else { "none" };
+    restart_button.style().set_property("display", restart_display).unwrap();
+
+    let ai_button = document().get_element_by_id("ai-button").unwrap()
+        .dyn_into::<web_sys::HtmlElement>().unwrap();
+    let ai_display = if ai_available && state.status == GameStatus::WaitingForPlayers { "block" } else { "none" };
+    ai_button.style().set_property("display", ai_display).unwrap();
     
     draw_scores(ctx, state);
 }
@@ -78,21 +73,7 @@ fn draw_scores(ctx: &CanvasRenderingContext2d, state: &GameState) {
     ctx.set_font("16px Arial");
     ctx.set_text_align("left");
 
-    let mut player_scores: Vec<_> = state
-        .players
-        .values()
-        .map(|player| {
-            let score = state
-                .grid
-                .iter()
-                .flatten()
-                .filter(|&&cell| cell == CellState::Owned(player.id))
-                .count();
-            (player, score)
-        })
-        .collect();
-
-    player_scores.sort_by(|a, b| b.1.cmp(&a.1));
+    let player_scores = state.ranking();
 
     let mut y_offset = 20.0;
     for (player, score) in player_scores {
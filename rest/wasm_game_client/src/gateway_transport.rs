@@ -0,0 +1,114 @@
+use super::transport::{RestartOutcome, Transport, TransportFuture};
+use game_kernel::grpc::{self, gamemovement, gamestate};
+use game_kernel::*;
+use serde::Deserialize;
+use std::cell::RefCell;
+
+// ===================================================================================
+// ADAPTADOR PARA O GATEWAY RUBY DA VERSÃO gRPC
+// ===================================================================================
+
+// O gateway expõe uma única partida, sem salas, IAs nem canal de push.
+const IN_PROGRESS_POLL_MS: i32 = 35;
+
+#[derive(Deserialize)]
+struct GatewayError {
+    error: String,
+}
+
+pub struct GatewayTransport {
+    api_base_url: String,
+    client: reqwest::Client,
+    session_token: RefCell<String>,
+}
+
+impl GatewayTransport {
+    pub fn new(api_base_url: String) -> Self {
+        GatewayTransport { api_base_url, client: reqwest::Client::new(), session_token: RefCell::new(String::new()) }
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/game/{}", self.api_base_url, path)
+    }
+}
+
+impl Transport for GatewayTransport {
+    fn join(&self) -> TransportFuture<'_, Player> {
+        Box::pin(async move {
+            let response = self.client.post(self.url("join")).send().await
+                .map_err(|e| format!("Erro de rede ao tentar /game/join: {}", e))?
+                .json::<gamestate::JoinGameResponse>().await
+                .map_err(|e| format!("Erro ao decodificar JSON do jogador: {}", e))?;
+            if !response.error.is_empty() {
+                return Err(format!("Falha ao entrar no jogo: {}", response.error));
+            }
+            *self.session_token.borrow_mut() = response.token;
+
+            response.player
+                .ok_or(WireError::MissingField { field: "JoinGameResponse.player" })
+                .and_then(Player::try_from)
+                .map_err(|e| format!("Resposta inválida do gateway: {}", e))
+        })
+    }
+
+    fn fetch_update(&self, _since: Option<u64>) -> TransportFuture<'_, StateUpdate> {
+        Box::pin(async move {
+            let response = self.client.get(self.url("state")).send().await
+                .map_err(|e| e.to_string())?
+                .json::<gamestate::GameStateResponse>().await
+                .map_err(|e| e.to_string())?;
+            let state = response.state
+                .ok_or(WireError::MissingField { field: "GameStateResponse.state" })
+                .and_then(GameState::try_from)
+                .map_err(|e| format!("Estado inválido recebido do gateway: {}", e))?;
+            Ok(StateUpdate::Snapshot(state))
+        })
+    }
+
+    fn send_move(&self, player_id: PlayerId, direction: Direction) -> TransportFuture<'_, ()> {
+        Box::pin(async move {
+            let payload = grpc::MovePayload::from(MovePayload { player_id, direction });
+            let session_token = self.session_token.borrow().clone();
+            let response = self.client.post(self.url("move")).bearer_auth(session_token).json(&payload).send().await
+                .map_err(|e| e.to_string())?
+                .json::<gamemovement::ExecuteMoveResponse>().await
+                .map_err(|e| e.to_string())?;
+            if !response.error.is_empty() {
+                return Err(response.error);
+            }
+            Ok(())
+        })
+    }
+
+    // O RestartGame esvazia a partida; cada cliente precisa entrar de novo.
+    fn restart(&self) -> TransportFuture<'_, RestartOutcome> {
+        Box::pin(async move {
+            let session_token = self.session_token.borrow().clone();
+            let resp = self.client.post(self.url("restart")).bearer_auth(session_token).send().await
+                .map_err(|e| e.to_string())?;
+            if resp.status().is_success() {
+                return Ok(RestartOutcome::Rejoin);
+            }
+            match resp.json::<GatewayError>().await {
+                Ok(body) => Err(body.error),
+                Err(_) => Err("Não foi possível reiniciar a partida".to_string()),
+            }
+        })
+    }
+
+    fn add_ai_player(&self, _difficulty: Difficulty) -> TransportFuture<'_, Player> {
+        Box::pin(async { Err("O gateway gRPC não oferece jogadores controlados por IA".to_string()) })
+    }
+
+    fn supports_ai(&self) -> bool {
+        false
+    }
+
+    fn push_url(&self) -> Option<String> {
+        None
+    }
+
+    fn in_progress_poll_ms(&self) -> i32 {
+        IN_PROGRESS_POLL_MS
+    }
+}
@@ -1,3 +1,6 @@
 mod app;
 mod drawing;
+mod gateway_transport;
+mod rest_transport;
+mod transport;
 mod utils;
@@ -0,0 +1,147 @@
+use super::transport::{RestartOutcome, Transport, TransportFuture};
+use super::utils::*;
+use game_kernel::*;
+use reqwest::Response;
+use std::cell::{Cell, RefCell};
+
+// ===================================================================================
+// ADAPTADOR PARA A API REST (GATEWAY P E SERVIÇOS A/B)
+// ===================================================================================
+
+// Mesmo intervalo do modo poll do cliente de terminal.
+const IN_PROGRESS_POLL_MS: i32 = 200;
+
+pub struct RestTransport {
+    api_base_url: String,
+    client: reqwest::Client,
+    room_id: Cell<RoomId>,
+    session_token: RefCell<String>,
+}
+
+async fn server_error(resp: Response, fallback: &str) -> String {
+    match resp.json::<ErrorBody>().await {
+        Ok(body) => body.erro,
+        Err(_) => fallback.to_string(),
+    }
+}
+
+impl RestTransport {
+    pub fn new(api_base_url: String) -> Self {
+        RestTransport {
+            api_base_url,
+            client: reqwest::Client::new(),
+            room_id: Cell::new(0),
+            session_token: RefCell::new(String::new()),
+        }
+    }
+
+    fn room_url(&self, path: &str) -> String {
+        format!("{}/game/{}/{}", self.api_base_url, self.room_id.get(), path)
+    }
+
+    async fn find_room(&self) -> Result<RoomId, String> {
+        if let Some(room_id) = query_param("sala").and_then(|value| value.parse().ok()) {
+            return Ok(room_id);
+        }
+
+        let rooms: Vec<RoomSummary> = self.client.get(format!("{}/game/rooms", self.api_base_url)).send().await
+            .map_err(|e| format!("Erro de rede ao listar salas: {}", e))?
+            .json().await
+            .map_err(|e| format!("Erro ao decodificar lista de salas: {}", e))?;
+
+        if let Some(room) = rooms.iter().find(|room| room.is_open()) {
+            return Ok(room.id);
+        }
+
+        log("Nenhuma sala aberta. Criando uma nova sala...");
+        let room: RoomSummary = self.client.post(format!("{}/game/rooms", self.api_base_url))
+            .json(&RoomConfig::default())
+            .send().await
+            .map_err(|e| format!("Erro de rede ao criar sala: {}", e))?
+            .json().await
+            .map_err(|e| format!("Erro ao decodificar sala criada: {}", e))?;
+        Ok(room.id)
+    }
+}
+
+impl Transport for RestTransport {
+    fn join(&self) -> TransportFuture<'_, Player> {
+        Box::pin(async move {
+            self.room_id.set(self.find_room().await?);
+            log(&format!("Tentando entrar na sala {}...", self.room_id.get()));
+
+            let resp = self.client.post(self.room_url("join")).send().await
+                .map_err(|e| format!("Erro de rede ao tentar /join: {}", e))?;
+            if !resp.status().is_success() {
+                let err_text = resp.text().await
+                    .map_err(|e| format!("Erro ao ler corpo da resposta de erro: {}", e))?;
+                return Err(format!("Falha ao entrar no jogo: {}", err_text));
+            }
+
+            let join_response = resp.json::<JoinResponse>().await
+                .map_err(|e| format!("Erro ao decodificar JSON do jogador: {}", e))?;
+            *self.session_token.borrow_mut() = join_response.token;
+            Ok(join_response.player)
+        })
+    }
+
+    fn fetch_update(&self, since: Option<u64>) -> TransportFuture<'_, StateUpdate> {
+        Box::pin(async move {
+            let url = match since {
+                Some(version) => format!("{}?since={}", self.room_url("state/updates"), version),
+                None => self.room_url("state/updates"),
+            };
+            let resp = self.client.get(&url).send().await.map_err(|e| e.to_string())?;
+            resp.json::<StateUpdate>().await.map_err(|e| e.to_string())
+        })
+    }
+
+    fn send_move(&self, player_id: PlayerId, direction: Direction) -> TransportFuture<'_, ()> {
+        Box::pin(async move {
+            let payload = MovePayload { player_id, direction };
+            let session_token = self.session_token.borrow().clone();
+            let resp = self.client.post(self.room_url("move")).bearer_auth(session_token).json(&payload).send().await
+                .map_err(|e| e.to_string())?;
+            if resp.status().is_success() {
+                return Ok(());
+            }
+            Err(server_error(resp, "Movimento recusado pelo servidor").await)
+        })
+    }
+
+    fn restart(&self) -> TransportFuture<'_, RestartOutcome> {
+        Box::pin(async move {
+            let request = RestartRequest { keep_players: true };
+            let session_token = self.session_token.borrow().clone();
+            let resp = self.client.post(self.room_url("restart")).bearer_auth(session_token).json(&request).send().await
+                .map_err(|e| e.to_string())?;
+            if resp.status().is_success() {
+                return Ok(RestartOutcome::SamePlayers);
+            }
+            Err(server_error(resp, "Não foi possível reiniciar a partida").await)
+        })
+    }
+
+    fn add_ai_player(&self, difficulty: Difficulty) -> TransportFuture<'_, Player> {
+        Box::pin(async move {
+            let request = AddAiRequest { difficulty, seed: None };
+            let resp = self.client.post(self.room_url("ai")).json(&request).send().await.map_err(|e| e.to_string())?;
+            if resp.status().is_success() {
+                return resp.json::<Player>().await.map_err(|e| e.to_string());
+            }
+            Err(server_error(resp, "Não foi possível adicionar a IA").await)
+        })
+    }
+
+    fn supports_ai(&self) -> bool {
+        true
+    }
+
+    fn push_url(&self) -> Option<String> {
+        Some(self.room_url("ws").replacen("http", "ws", 1))
+    }
+
+    fn in_progress_poll_ms(&self) -> i32 {
+        IN_PROGRESS_POLL_MS
+    }
+}
@@ -0,0 +1,45 @@
+use game_kernel::*;
+use std::future::Future;
+use std::pin::Pin;
+use std::str::FromStr;
+
+// ===================================================================================
+// TRANSPORTE ENTRE O CLIENTE E O BACKEND
+// ===================================================================================
+
+pub type TransportFuture<'a, T> = Pin<Box<dyn Future<Output = Result<T, String>> + 'a>>;
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum RestartOutcome {
+    SamePlayers,
+    Rejoin,
+}
+
+pub trait Transport {
+    fn join(&self) -> TransportFuture<'_, Player>;
+    fn fetch_update(&self, since: Option<u64>) -> TransportFuture<'_, StateUpdate>;
+    fn send_move(&self, player_id: PlayerId, direction: Direction) -> TransportFuture<'_, ()>;
+    fn restart(&self) -> TransportFuture<'_, RestartOutcome>;
+    fn add_ai_player(&self, difficulty: Difficulty) -> TransportFuture<'_, Player>;
+    fn supports_ai(&self) -> bool;
+    fn push_url(&self) -> Option<String>;
+    fn in_progress_poll_ms(&self) -> i32;
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TransportKind {
+    Rest,
+    Grpc,
+}
+
+impl FromStr for TransportKind {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, String> {
+        match value {
+            "rest" => Ok(TransportKind::Rest),
+            "grpc" => Ok(TransportKind::Grpc),
+            other => Err(format!("Transporte desconhecido '{}': use rest ou grpc", other)),
+        }
+    }
+}
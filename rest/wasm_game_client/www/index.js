@@ -4,12 +4,15 @@ async function run() {
   try {
     await init();
 
-    const apiPort = '8000'; 
+    // ?transporte=grpc usa o gateway Ruby da versão gRPC; o padrão é a API REST.
+    const params = new URLSearchParams(window.location.search);
+    const transport = params.get('transporte') ?? 'rest';
+    const apiPort = transport === 'grpc' ? '8082' : '8000';
     const apiBaseUrl = `http://${window.location.hostname}:${apiPort}`;
 
-    console.log(`API URL: ${apiBaseUrl}`);
+    console.log(`API URL: ${apiBaseUrl} (${transport})`);
 
-    const app = new App(apiBaseUrl);
+    const app = new App(apiBaseUrl, transport);
     await app.run_app();
 
   } catch (error) {